@@ -5,9 +5,21 @@ use cfg_if::cfg_if;
 cfg_if! (
     if #[cfg(feature = "full")] {
         extern crate alloc;
+
+        #[doc(hidden)]
+        pub use alloc::vec as __vec;
+    }
+);
+
+cfg_if! (
+    if #[cfg(feature = "runtime-simd")] {
+        extern crate std;
     }
 );
 
+mod macros;
+
+pub mod errors;
 pub mod vectors;
 pub mod matricies;
 pub mod prelude;
\ No newline at end of file
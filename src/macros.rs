@@ -0,0 +1,27 @@
+#![cfg(feature = "full")]
+
+#[macro_export]
+macro_rules! vector {
+    () => {
+        $crate::vectors::Vector::from($crate::__vec::Vec::new())
+    };
+    ($value:expr; $len:expr) => {
+        $crate::vectors::Vector::from($crate::__vec![$value; $len])
+    };
+    ($($value:expr),+ $(,)?) => {
+        $crate::vectors::Vector::from($crate::__vec![$($value),+])
+    };
+}
+
+#[macro_export]
+macro_rules! matrix {
+    () => {
+        $crate::matricies::Matrix::from_rows($crate::__vec::Vec::new())
+    };
+    ($([$($value:expr),* $(,)?]),+ $(,)?) => {
+        $crate::matricies::Matrix::from_rows($crate::__vec![$($crate::vector![$($value),*]),+])
+    };
+    ($value:expr; ($rows:expr, $cols:expr)) => {
+        $crate::matricies::Matrix::filled($rows, $cols, $value)
+    };
+}
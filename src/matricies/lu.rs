@@ -0,0 +1,324 @@
+#![cfg(feature = "full")]
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use num_traits::Float;
+use crate::matricies::{LuDecomposition, Matrix};
+use crate::vectors::{Vector, VectorType};
+
+impl<T> Matrix<T>
+where
+    T: Float {
+    /// LU decomposition with partial pivoting.
+    ///
+    /// Returns `(L, U, permutation)` such that row `i` of `L * U` is row
+    /// `permutation[i]` of `self`. `L` is unit lower triangular and `U` is upper
+    /// triangular. Returns `None` when the matrix is (numerically) singular,
+    /// i.e. when a pivot's magnitude falls to `n * T::epsilon() * max(|a_ij|)`
+    /// or below. The tolerance is relative, so rescaling the matrix does not
+    /// change the outcome.
+    pub fn lu(&self) -> Option<(Matrix<T>, Matrix<T>, Vec<usize>)> {
+        if self.rows != self.cols {
+            panic!(
+                "Cannot find the LU decomposition of a non-square {}x{} matrix.",
+                self.rows, self.cols
+            )
+        }
+
+        let largest = self.values.iter().fold(T::zero(), |largest, value| largest.max(value.abs()));
+        let tolerance = T::from(self.rows).unwrap_or_else(T::one) * T::epsilon() * largest;
+        self.decompose(tolerance)
+    }
+
+    /// Elimination behind `lu`, giving up on the first pivot whose magnitude is
+    /// at most `tolerance` (or `NaN`). Assumes the matrix is square.
+    fn decompose(&self, tolerance: T) -> Option<(Matrix<T>, Matrix<T>, Vec<usize>)> {
+        let n = self.rows;
+        let mut lower = vec![T::zero(); n * n];
+        let mut upper = self.values.clone();
+        let mut permutation = (0..n).collect::<Vec<usize>>();
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&lhs, &rhs| {
+                    upper[lhs * n + k].abs()
+                        .partial_cmp(&upper[rhs * n + k].abs())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .unwrap_or(k);
+
+            let pivot_value = upper[pivot * n + k];
+            if pivot_value.is_nan() || pivot_value.abs() <= tolerance {
+                return None
+            }
+
+            if pivot != k {
+                for col in 0..n {
+                    upper.swap(k * n + col, pivot * n + col)
+                }
+                for col in 0..k {
+                    lower.swap(k * n + col, pivot * n + col)
+                }
+                permutation.swap(k, pivot)
+            }
+
+            for row in k + 1..n {
+                let factor = upper[row * n + k] / upper[k * n + k];
+                lower[row * n + k] = factor;
+                upper[row * n + k] = T::zero();
+                for col in k + 1..n {
+                    upper[row * n + col] = upper[row * n + col] - factor * upper[k * n + col]
+                }
+            }
+        }
+
+        for idx in 0..n {
+            lower[idx * n + idx] = T::one()
+        }
+
+        Some((
+            Matrix { values: lower, rows: n, cols: n },
+            Matrix { values: upper, rows: n, cols: n },
+            permutation
+        ))
+    }
+
+    /// Rank computed by Gaussian elimination with partial pivoting.
+    ///
+    /// Pivots with a magnitude at or below
+    /// `max(rows, cols) * T::epsilon() * max(|a_ij|)` are treated as zero.
+    pub fn rank(&self) -> usize {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut values = self.values.clone();
+
+        let largest = values.iter().fold(T::zero(), |largest, value| largest.max(value.abs()));
+        let tolerance = T::from(rows.max(cols)).unwrap_or_else(T::one) * T::epsilon() * largest;
+
+        let mut rank = 0;
+        for col in 0..cols {
+            if rank == rows {
+                break
+            }
+
+            let pivot = (rank..rows)
+                .max_by(|&lhs, &rhs| {
+                    values[lhs * cols + col].abs()
+                        .partial_cmp(&values[rhs * cols + col].abs())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .unwrap_or(rank);
+
+            if values[pivot * cols + col].abs() <= tolerance {
+                continue
+            }
+
+            for idx in 0..cols {
+                values.swap(rank * cols + idx, pivot * cols + idx)
+            }
+            for row in rank + 1..rows {
+                let factor = values[row * cols + col] / values[rank * cols + col];
+                for idx in col..cols {
+                    values[row * cols + idx] = values[row * cols + idx] - factor * values[rank * cols + idx]
+                }
+            }
+            rank += 1
+        }
+        rank
+    }
+
+    /// Determinant computed by the same elimination as `lu`, but without its
+    /// singularity tolerance: only an exactly zero pivot gives a determinant of
+    /// zero, so nearly singular and badly scaled matrices keep their value.
+    pub fn determinant(&self) -> T {
+        if self.rows != self.cols {
+            panic!(
+                "Cannot find the determinant of a non-square {}x{} matrix.",
+                self.rows, self.cols
+            )
+        }
+
+        let (_, upper, permutation) = match self.decompose(T::zero()) {
+            Some(decomposition) => decomposition,
+            None => return T::zero()
+        };
+
+        // a permutation with `c` cycles over `n` elements takes `n - c` swaps
+        let mut visited = vec![false; permutation.len()];
+        let mut swaps = 0;
+        for start in 0..permutation.len() {
+            if visited[start] {
+                continue
+            }
+
+            let mut idx = start;
+            let mut cycle_length = 0;
+            while !visited[idx] {
+                visited[idx] = true;
+                idx = permutation[idx];
+                cycle_length += 1
+            }
+            swaps += cycle_length - 1
+        }
+
+        let n = upper.rows;
+        let product = (0..n).fold(T::one(), |product, idx| product * upper.values[idx * n + idx]);
+        if swaps % 2 == 0 {
+            product
+        } else {
+            -product
+        }
+    }
+
+    /// Factors the matrix once so that `LuDecomposition::solve` can be reused
+    /// across many right-hand sides. Returns `None` for singular matrices.
+    pub fn factorize(&self) -> Option<LuDecomposition<T>> {
+        self.lu().map(|(lower, upper, permutation)| LuDecomposition {
+            lower,
+            upper,
+            permutation,
+        })
+    }
+
+    /// Solves `self * x = b`, returning `None` when `self` is singular.
+    pub fn solve(&self, b: &impl VectorType<T>) -> Option<Vector<T>> {
+        self.factorize().map(|decomposition| decomposition.solve(b))
+    }
+}
+
+impl<T> LuDecomposition<T>
+where
+    T: Float {
+    pub fn lower(&self) -> &Matrix<T> {
+        &self.lower
+    }
+
+    pub fn upper(&self) -> &Matrix<T> {
+        &self.upper
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn solve(&self, b: &impl VectorType<T>) -> Vector<T> {
+        let n = self.permutation.len();
+        if b.len() != n {
+            panic!(
+                "Cannot solve a {}x{} system with a right-hand side of length {}.",
+                n, n, b.len()
+            )
+        }
+
+        let b = b.iter().as_slice();
+
+        // forward substitution: L * y = P * b
+        let mut values = Vec::with_capacity(n);
+        for row in 0..n {
+            let mut value = b[self.permutation[row]];
+            for (col, solved) in values.iter().enumerate() {
+                value = value - self.lower.values[row * n + col] * *solved
+            }
+            values.push(value)
+        }
+
+        // back substitution: U * x = y
+        for row in (0..n).rev() {
+            let mut value = values[row];
+            for (col, solved) in values.iter().enumerate().skip(row + 1) {
+                value = value - self.upper.values[row * n + col] * *solved
+            }
+            values[row] = value / self.upper.values[row * n + row]
+        }
+
+        Vector::from(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, vector};
+    use crate::matricies::Matrix;
+    use crate::vectors::{Vector, VectorType};
+
+    fn assert_close(lhs: &Matrix<f64>, rhs: &Matrix<f64>) {
+        assert_eq!((lhs.rows(), lhs.cols()), (rhs.rows(), rhs.cols()));
+        assert!(lhs.values.iter().zip(rhs.values.iter()).all(|(lhs, rhs)| (lhs - rhs).abs() < 1e-12))
+    }
+
+    #[test]
+    fn lower_times_upper_is_permuted_input() {
+        let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]];
+        let (lower, upper, permutation) = matrix.lu().unwrap();
+
+        let permuted = Matrix::from_rows(
+            permutation.iter().map(|&row| Vector::from(matrix.row(row))).collect()
+        );
+        assert_close(&(&lower * &upper), &permuted);
+        assert_ne!(permutation, [0, 1, 2]);
+    }
+
+    #[test]
+    fn singular_matrix_has_no_decomposition() {
+        let matrix = matrix![[1.0, 2.0], [2.0, 4.0]];
+
+        assert!(matrix.lu().is_none());
+        assert_eq!(matrix.determinant(), 0.0);
+    }
+
+    #[test]
+    fn row_swap_negates_determinant() {
+        let matrix: Matrix<f64> = matrix![[2.0, 1.0], [1.0, 3.0]];
+        let swapped: Matrix<f64> = matrix![[1.0, 3.0], [2.0, 1.0]];
+
+        assert!((matrix.determinant() - 5.0).abs() < 1e-12);
+        assert!((swapped.determinant() + 5.0).abs() < 1e-12);
+        assert_eq!(matrix![[0.0, 1.0], [1.0, 0.0]].determinant(), -1.0);
+    }
+
+    #[test]
+    fn determinant_ignores_singularity_tolerance() {
+        assert_eq!(matrix![[1.0, 0.0], [0.0, 1e-20]].determinant(), 1e-20);
+        assert_eq!(matrix![[1e8, 0.0], [0.0, 1e-8]].determinant(), 1.0);
+    }
+
+    fn assert_residual_small(matrix: &Matrix<f64>, x: &Vector<f64>, b: &Vector<f64>) {
+        let residual = &(matrix * x) - b;
+        assert!(residual.iter().all(|value| value.abs() < 1e-12))
+    }
+
+    #[test]
+    fn solve_needs_pivot_swap() {
+        let matrix = matrix![[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]];
+        let b = vector![5.0, 3.0, 4.0];
+        let x = matrix.solve(&b).unwrap();
+
+        assert_residual_small(&matrix, &x, &b);
+        assert!(x.iter().zip([1.0, 2.0, 1.0].iter()).all(|(lhs, rhs)| (lhs - rhs).abs() < 1e-12));
+    }
+
+    #[test]
+    fn decomposition_is_reused_across_right_hand_sides() {
+        let matrix = matrix![[0.0, 1.0], [2.0, 3.0]];
+        let decomposition = matrix.factorize().unwrap();
+
+        for b in [vector![1.0, 2.0], vector![-4.0, 0.5]] {
+            assert_residual_small(&matrix, &decomposition.solve(&b), &b)
+        }
+    }
+
+    #[test]
+    fn singular_system_has_no_solution() {
+        let matrix = matrix![[1.0, 2.0], [2.0, 4.0]];
+
+        assert!(matrix.factorize().is_none());
+        assert!(matrix.solve(&vector![1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot solve a 2x2 system with a right-hand side of length 3.")]
+    fn solve_checks_right_hand_side_length() {
+        let matrix = matrix![[1.0, 0.0], [0.0, 1.0]];
+        matrix.solve(&vector![1.0, 2.0, 3.0]);
+    }
+}
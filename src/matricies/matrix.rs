@@ -0,0 +1,248 @@
+#![cfg(feature = "full")]
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Index;
+use num_traits::{One, Zero};
+use crate::matricies::Matrix;
+use crate::vectors::{StrideSlice, Vector, VectorSlice, VectorType};
+
+impl<T> Matrix<T> {
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone {
+        Matrix {
+            values: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Self
+    where
+        T: Clone + Zero {
+        Matrix::filled(rows, cols, T::zero())
+    }
+
+    pub fn identity(n: usize) -> Self
+    where
+        T: Clone + Zero + One {
+        let mut matrix = Matrix::zeros(n, n);
+        for idx in 0..n {
+            matrix.values[idx * n + idx] = T::one()
+        }
+        matrix
+    }
+
+    pub fn diagonal(values: &impl VectorType<T>) -> Self
+    where
+        T: Clone + Zero {
+        let n = values.len();
+        let mut matrix = Matrix::zeros(n, n);
+        for (idx, value) in values.iter().enumerate() {
+            matrix.values[idx * n + idx] = value.clone()
+        }
+        matrix
+    }
+
+    pub fn from_rows(rows: Vec<Vector<T>>) -> Self {
+        let row_count = rows.len();
+        let col_count = rows.first().map_or(0, |row| row.len());
+
+        let mut values = Vec::with_capacity(row_count * col_count);
+        for row in rows {
+            if row.len() != col_count {
+                panic!("Matrix rows must all have the same length.")
+            }
+            values.extend(row.values)
+        }
+
+        Matrix {
+            values,
+            rows: row_count,
+            cols: col_count,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.values.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, index: usize) -> VectorSlice<'_, T> {
+        if index >= self.rows {
+            panic!("Row index {} is out of bounds for a matrix with {} rows.", index, self.rows)
+        }
+
+        let start = index * self.cols;
+        VectorSlice::from(&self.values[start..start + self.cols])
+    }
+
+    /// Borrows a column. Columns are strided in the row-major storage, so this
+    /// is a `StrideSlice` rather than a `VectorSlice`.
+    pub fn col(&self, index: usize) -> StrideSlice<'_, T> {
+        if index >= self.cols {
+            panic!("Column index {} is out of bounds for a matrix with {} columns.", index, self.cols)
+        }
+
+        // a matrix with no rows has no storage to offset into
+        StrideSlice::new(self.values.get(index..).unwrap_or(&[]), self.cols, self.rows)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = VectorSlice<'_, T>> {
+        (0..self.rows).map(|index| self.row(index))
+    }
+
+    /// Iterates over borrowed columns; see `col`. `StrideSlice` provides the
+    /// reductions that do not need contiguous storage (`sum`, `dot`, `norm`);
+    /// for the rest of the vector API, copy a column out with `to_vector`.
+    pub fn iter_cols(&self) -> impl Iterator<Item = StrideSlice<'_, T>> {
+        (0..self.cols).map(|index| self.col(index))
+    }
+
+    pub fn trace(&self) -> T
+    where
+        T: Clone + Zero {
+        if self.rows != self.cols {
+            panic!("Cannot find the trace of a non-square {}x{} matrix.", self.rows, self.cols)
+        }
+
+        (0..self.rows).fold(T::zero(), |sum, idx| sum + self.values[idx * self.cols + idx].clone())
+    }
+
+    /// Extracts the main diagonal. For non-square matrices this has
+    /// `min(rows, cols)` elements.
+    pub fn main_diagonal(&self) -> Vector<T>
+    where
+        T: Clone {
+        Vector::from(
+            (0..self.rows.min(self.cols))
+                .map(|idx| self.values[idx * self.cols + idx].clone())
+                .collect::<Vec<T>>()
+        )
+    }
+
+    /// Flattens the matrix into its row-major backing storage without copying.
+    pub fn into_vector(self) -> Vector<T> {
+        Vector::from(self.values)
+    }
+
+    pub fn transpose(&self) -> Matrix<T>
+    where
+        T: Clone {
+        let mut values = Vec::with_capacity(self.values.len());
+        for col in 0..self.cols {
+            for row in 0..self.rows {
+                values.push(self.values[row * self.cols + col].clone())
+            }
+        }
+
+        Matrix {
+            values,
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Transposes a square matrix in place.
+    ///
+    /// In-place transposition of a non-square matrix is unsupported and panics;
+    /// use `transpose` instead.
+    pub fn transpose_mut(&mut self) -> &mut Self {
+        if self.rows != self.cols {
+            panic!(
+                "Cannot transpose a non-square {}x{} matrix in place.",
+                self.rows, self.cols
+            )
+        }
+
+        for row in 0..self.rows {
+            for col in row + 1..self.cols {
+                self.values.swap(row * self.cols + col, col * self.cols + row)
+            }
+        }
+        self
+    }
+}
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        match self.get(row, col) {
+            Some(value) => value,
+            None => panic!(
+                "Index ({}, {}) is out of bounds for a {}x{} matrix.",
+                row, col, self.rows, self.cols
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, vector};
+    use crate::matricies::Matrix;
+    use crate::vectors::VectorType;
+
+    #[test]
+    fn transpose_2x3_into_3x2() {
+        let matrix: Matrix<i32> = matrix![[1, 2, 3], [4, 5, 6]];
+        let transposed = matrix.transpose();
+
+        assert_eq!((transposed.rows(), transposed.cols()), (3, 2));
+        for row in 0..2 {
+            for col in 0..3 {
+                assert_eq!(transposed[(col, row)], matrix[(row, col)])
+            }
+        }
+    }
+
+    #[test]
+    fn matrix_vector_product() {
+        let matrix: Matrix<i32> = matrix![[1, 2, 3], [4, 5, 6]];
+
+        assert!((&matrix * &vector![1, 0, -1]).iter().eq([-2, -2].iter()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot multiply a 2x3 matrix by a vector of length 2.")]
+    fn matrix_vector_product_checks_dimensions() {
+        let matrix: Matrix<i32> = matrix![[1, 2, 3], [4, 5, 6]];
+        let _ = &matrix * &vector![1, 2];
+    }
+
+    #[test]
+    fn columns_of_a_matrix_without_rows() {
+        let matrix: Matrix<i32> = Matrix::zeros(0, 3);
+
+        assert!(matrix.col(1).is_empty());
+        assert_eq!(matrix.iter_cols().count(), 3);
+    }
+
+    #[test]
+    fn non_square_matrix_product() {
+        let lhs: Matrix<i32> = matrix![[1, 2, 3], [4, 5, 6]];
+        let rhs: Matrix<i32> = matrix![[7, 8], [9, 10], [11, 12]];
+
+        assert_eq!(&lhs * &rhs, matrix![[58, 64], [139, 154]]);
+        assert_eq!(rhs * lhs, matrix![[39, 54, 69], [49, 68, 87], [59, 82, 105]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot multiply a 2x3 matrix by a 2x3 matrix.")]
+    fn matrix_product_checks_dimensions() {
+        let matrix: Matrix<i32> = matrix![[1, 2, 3], [4, 5, 6]];
+        let _ = &matrix * &matrix;
+    }
+}
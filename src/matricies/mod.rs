@@ -0,0 +1,108 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "full")] {
+        use alloc::vec::Vec;
+        use core::ops::{Add, Mul};
+        use crate::vectors::Vector;
+
+        mod matrix;
+        mod lu;
+        mod power_iteration;
+
+        pub use power_iteration::power_iteration;
+
+        /// A dense matrix stored in row-major order.
+        ///
+        /// ```
+        /// use adv_linalg_lib::matrix;
+        /// use adv_linalg_lib::matricies::Matrix;
+        ///
+        /// assert_eq!(Matrix::<f64>::identity(2), matrix![[1.0, 0.0], [0.0, 1.0]]);
+        /// ```
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Matrix<T> {
+            pub(crate) values: Vec<T>,
+            pub(crate) rows: usize,
+            pub(crate) cols: usize,
+        }
+
+        pub struct LuDecomposition<T> {
+            lower: Matrix<T>,
+            upper: Matrix<T>,
+            permutation: Vec<usize>,
+        }
+
+        impl<T> Mul for &Matrix<T>
+        where
+            T: Clone + Default + Add<Output = T> + Mul<Output = T> {
+            type Output = Matrix<T>;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                if self.cols != rhs.rows {
+                    panic!(
+                        "Cannot multiply a {}x{} matrix by a {}x{} matrix.",
+                        self.rows, self.cols, rhs.rows, rhs.cols
+                    )
+                }
+
+                // columns of `rhs` are the rows of its transpose
+                let rhs_transpose = rhs.transpose();
+
+                let mut values = Vec::with_capacity(self.rows * rhs.cols);
+                for row in 0..self.rows {
+                    for col in 0..rhs.cols {
+                        values.push(self.row(row) * rhs_transpose.row(col))
+                    }
+                }
+
+                Matrix {
+                    values,
+                    rows: self.rows,
+                    cols: rhs.cols,
+                }
+            }
+        }
+
+        impl<T> Mul for Matrix<T>
+        where
+            T: Clone + Default + Add<Output = T> + Mul<Output = T> {
+            type Output = Matrix<T>;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                &self * &rhs
+            }
+        }
+
+        impl<T> Mul<&Vector<T>> for &Matrix<T>
+        where
+            T: Clone + Default + Add<Output = T> + Mul<Output = T> {
+            type Output = Vector<T>;
+
+            fn mul(self, rhs: &Vector<T>) -> Self::Output {
+                if self.cols != rhs.len() {
+                    panic!(
+                        "Cannot multiply a {}x{} matrix by a vector of length {}.",
+                        self.rows, self.cols, rhs.len()
+                    )
+                }
+
+                Vector::from(
+                    (0..self.rows)
+                        .map(|row| self.row(row) * rhs.as_slice(0..rhs.len()))
+                        .collect::<Vec<T>>()
+                )
+            }
+        }
+
+        impl<T> Mul<Vector<T>> for Matrix<T>
+        where
+            T: Clone + Default + Add<Output = T> + Mul<Output = T> {
+            type Output = Vector<T>;
+
+            fn mul(self, rhs: Vector<T>) -> Self::Output {
+                &self * &rhs
+            }
+        }
+    }
+}
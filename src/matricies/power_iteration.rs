@@ -0,0 +1,74 @@
+#![cfg(feature = "full")]
+
+use alloc::vec;
+use num_traits::Float;
+use crate::matricies::Matrix;
+use crate::vectors::{MutVector, Vector, VectorType};
+
+/// Estimates the dominant eigenvalue of a square matrix and a unit eigenvector
+/// for it by power iteration.
+///
+/// Starting from the normalized all-ones vector, each step multiplies by
+/// `matrix`, renormalizes and takes the Rayleigh quotient as the eigenvalue
+/// estimate. Stops after `iters` steps, or earlier once the estimate changes by
+/// less than `tol`. If the product vanishes, the current vector is in the null
+/// space and `(0, vector)` is returned.
+///
+/// Convergence needs a single eigenvalue of largest magnitude and a starting
+/// vector that is not orthogonal to its eigenvector.
+pub fn power_iteration<T>(matrix: &Matrix<T>, iters: usize, tol: T) -> (T, Vector<T>)
+where
+    T: Float + Default {
+    if matrix.rows != matrix.cols {
+        panic!("Cannot run power iteration on a non-square {}x{} matrix.", matrix.rows, matrix.cols)
+    }
+
+    let mut vector = MutVector::from(vec![T::one(); matrix.rows]);
+    vector.try_normalize_mut(T::epsilon());
+
+    let mut eigenvalue = T::zero();
+    for _ in 0..iters {
+        let current = Vector::from(vector);
+        let product = matrix * &current;
+        let estimate = product.dot(&current);
+
+        let mut next = MutVector::from(product);
+        if !next.try_normalize_mut(T::epsilon()) {
+            return (T::zero(), current)
+        }
+
+        let change = (estimate - eigenvalue).abs();
+        eigenvalue = estimate;
+        vector = next;
+        if change < tol {
+            break
+        }
+    }
+    (eigenvalue, Vector::from(vector))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix;
+    use crate::vectors::VectorType;
+    use super::power_iteration;
+
+    #[test]
+    fn finds_dominant_eigenpair() {
+        let matrix = matrix![[2.0, 1.0], [1.0, 2.0]];
+        let (eigenvalue, eigenvector) = power_iteration(&matrix, 100, 1e-12);
+
+        assert!((eigenvalue - 3.0f64).abs() < 1e-9);
+        let expected = 0.5f64.sqrt();
+        assert!(eigenvector.iter().all(|value| (value - expected).abs() < 1e-6));
+    }
+
+    #[test]
+    fn null_space_start_gives_zero() {
+        let matrix = matrix![[1.0, -1.0], [1.0, -1.0]];
+        let (eigenvalue, eigenvector) = power_iteration(&matrix, 10, 1e-12);
+
+        assert_eq!(eigenvalue, 0.0);
+        assert!((eigenvector.norm() - 1.0f64).abs() < 1e-12);
+    }
+}
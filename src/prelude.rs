@@ -2,12 +2,25 @@ use cfg_if::cfg_if;
 
 cfg_if! {
     if #[cfg(feature = "no_std")]  {
-        pub use crate::vectors::{VectorSlice, MutVectorSlice};
+        pub use crate::vectors::{VectorType, VectorSlice, MutVectorSlice, StrideSlice, ArrayVector};
     }
 }
 
 cfg_if! {
     if #[cfg(feature = "full")] {
         pub use crate::vectors::{Vector, MutVector};
+        pub use crate::matricies::Matrix;
+    }
+}
+
+cfg_if! {
+    if #[cfg(feature = "heapless")] {
+        pub use crate::vectors::HeaplessVector;
+    }
+}
+
+cfg_if! {
+    if #[cfg(feature = "num-complex")] {
+        pub use crate::vectors::ComplexVectorType;
     }
 }
\ No newline at end of file
@@ -0,0 +1,110 @@
+#![cfg(feature = "no_std")]
+
+use core::array;
+use core::ops::{Add, Index, Mul, Range, Sub};
+use core::slice::Iter;
+use crate::vectors::{ArrayVector, VectorSlice, VectorType};
+
+impl<T, const N: usize> ArrayVector<T, N> {
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    pub fn as_slice(&self, range: Range<usize>) -> VectorSlice<'_, T> {
+        VectorSlice {
+            values: self.values
+                        .split_at(range.start).1
+                        .split_at(range.len()).0
+        }
+    }
+}
+impl<T, const N: usize> From<[T; N]> for ArrayVector<T, N> {
+    fn from(values: [T; N]) -> Self {
+        ArrayVector { values }
+    }
+}
+impl<T, const N: usize> Index<usize> for ArrayVector<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+impl<T, const N: usize> VectorType<T> for ArrayVector<T, N> {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        self.values.iter()
+    }
+}
+
+impl<T, const N: usize> Add for &ArrayVector<T, N>
+where
+    T: Clone + Add<Output = T> {
+    type Output = ArrayVector<T, N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        ArrayVector {
+            values: array::from_fn(|idx| self.values[idx].clone() + rhs.values[idx].clone())
+        }
+    }
+}
+impl<T, const N: usize> Add for ArrayVector<T, N>
+where
+    T: Clone + Add<Output = T> {
+    type Output = ArrayVector<T, N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<T, const N: usize> Sub for &ArrayVector<T, N>
+where
+    T: Clone + Sub<Output = T> {
+    type Output = ArrayVector<T, N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        ArrayVector {
+            values: array::from_fn(|idx| self.values[idx].clone() - rhs.values[idx].clone())
+        }
+    }
+}
+impl<T, const N: usize> Sub for ArrayVector<T, N>
+where
+    T: Clone + Sub<Output = T> {
+    type Output = ArrayVector<T, N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<T, const N: usize> Mul for &ArrayVector<T, N>
+where
+    T: Clone + Default + Add<Output = T> + Mul<Output = T> {
+    type Output = T;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut product = T::default();
+        for idx in 0..N {
+            product = product + self.values[idx].clone() * rhs.values[idx].clone()
+        }
+        product
+    }
+}
+impl<T, const N: usize> Mul for ArrayVector<T, N>
+where
+    T: Clone + Default + Add<Output = T> + Mul<Output = T> {
+    type Output = T;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
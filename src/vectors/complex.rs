@@ -0,0 +1,46 @@
+#![cfg(feature = "num-complex")]
+
+use core::ops::Neg;
+use cfg_if::cfg_if;
+use num_complex::Complex;
+use num_traits::{Num, Zero};
+use crate::vectors::VectorType;
+
+cfg_if! {
+    if #[cfg(feature = "full")] {
+        use alloc::vec::Vec;
+        use crate::vectors::Vector;
+    }
+}
+
+pub trait ComplexVectorType<T>: VectorType<Complex<T>> {
+    #[cfg(feature = "full")]
+    fn conj(&self) -> Vector<Complex<T>>
+    where
+        T: Clone + Num + Neg<Output = T> {
+        Vector::from(
+            self.iter()
+                .map(|value| value.conj())
+                .collect::<Vec<Complex<T>>>()
+        )
+    }
+
+    /// Hermitian inner product, conjugating `self` before multiplying.
+    ///
+    /// Unlike the `*` dot product, this is the correct inner product for
+    /// complex vectors: `v.dot_hermitian(&v)` is the squared norm of `v`.
+    fn dot_hermitian(&self, other: &impl VectorType<Complex<T>>) -> Complex<T>
+    where
+        T: Clone + Num + Neg<Output = T> {
+        if self.len() != other.len() {
+            panic!("Cannot find dot product of two differently sized vectors.")
+        }
+
+        self.iter()
+            .zip(other.iter())
+            .fold(Complex::zero(), |sum, (lhs, rhs)| sum + lhs.conj() * rhs.clone())
+    }
+}
+impl<T, V> ComplexVectorType<T> for V
+where
+    V: VectorType<Complex<T>> {}
@@ -0,0 +1,34 @@
+#![cfg(feature = "full")]
+
+use alloc::vec::Vec;
+use num_traits::Float;
+use crate::vectors::{Vector, VectorType};
+
+/// Orthonormalizes `vectors` with the (modified) Gram-Schmidt process.
+///
+/// Each input has its projections onto the already accepted basis vectors
+/// removed. If what remains has a norm of at most `sqrt(T::epsilon())` times
+/// the input's original norm, the input is treated as linearly dependent on
+/// the previous ones and is dropped, so the output may be shorter than the
+/// input. Zero vectors are always dropped.
+pub fn gram_schmidt<T>(vectors: &[Vector<T>]) -> Vec<Vector<T>>
+where
+    T: Float {
+    let tolerance = T::epsilon().sqrt();
+
+    let mut basis: Vec<Vector<T>> = Vec::with_capacity(vectors.len());
+    for vector in vectors {
+        let original_norm = vector.norm();
+
+        let mut residual = Vector::from(vector.values.clone());
+        for basis_vector in &basis {
+            residual = &residual - &residual.project_onto(basis_vector)
+        }
+
+        let residual_norm = residual.norm();
+        if residual_norm > tolerance * original_norm {
+            basis.push(residual.normalize())
+        }
+    }
+    basis
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "heapless")]
+
+use core::ops::{Add, Index, Mul, Range, Sub};
+use core::slice::Iter;
+use crate::errors::CapacityError;
+use crate::vectors::{HeaplessVector, VectorSlice, VectorType};
+
+impl<T, const N: usize> HeaplessVector<T, N> {
+    pub fn new() -> Self {
+        HeaplessVector { values: heapless::Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn as_slice(&self, range: Range<usize>) -> VectorSlice<'_, T> {
+        VectorSlice {
+            values: self.values
+                        .as_slice()
+                        .split_at(range.start).1
+                        .split_at(range.len()).0
+        }
+    }
+
+    pub fn push(&mut self, value: T) -> Result<&mut Self, CapacityError> {
+        match self.values.push(value) {
+            Ok(()) => Ok(self),
+            Err(_) => Err(CapacityError { capacity: N })
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.values.pop()
+    }
+}
+impl<T, const N: usize> Default for HeaplessVector<T, N> {
+    fn default() -> Self {
+        HeaplessVector::new()
+    }
+}
+impl<T, const N: usize> From<heapless::Vec<T, N>> for HeaplessVector<T, N> {
+    fn from(values: heapless::Vec<T, N>) -> Self {
+        HeaplessVector { values }
+    }
+}
+impl<T, const N: usize> TryFrom<&[T]> for HeaplessVector<T, N>
+where
+    T: Clone {
+    type Error = CapacityError;
+
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        match heapless::Vec::from_slice(values) {
+            Ok(values) => Ok(HeaplessVector { values }),
+            Err(()) => Err(CapacityError { capacity: N })
+        }
+    }
+}
+impl<T, const N: usize> Index<usize> for HeaplessVector<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+impl<T, const N: usize> VectorType<T> for HeaplessVector<T, N> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        self.values.iter()
+    }
+}
+
+impl<T, const N: usize> Add for &HeaplessVector<T, N>
+where
+    T: Clone + Add<Output = T> {
+    type Output = HeaplessVector<T, N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.len() != rhs.len() {
+            panic!("Vectors with different sizes cannot be added together.")
+        }
+
+        // both operands hold at most `N` elements, so collecting cannot overflow
+        HeaplessVector {
+            values: self.values
+                        .iter()
+                        .zip(rhs.values.iter())
+                        .map(|(lhs, rhs)| lhs.clone() + rhs.clone())
+                        .collect()
+        }
+    }
+}
+impl<T, const N: usize> Add for HeaplessVector<T, N>
+where
+    T: Clone + Add<Output = T> {
+    type Output = HeaplessVector<T, N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<T, const N: usize> Sub for &HeaplessVector<T, N>
+where
+    T: Clone + Sub<Output = T> {
+    type Output = HeaplessVector<T, N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.len() != rhs.len() {
+            panic!("Vectors with different sizes cannot be subtracted together.")
+        }
+
+        // both operands hold at most `N` elements, so collecting cannot overflow
+        HeaplessVector {
+            values: self.values
+                        .iter()
+                        .zip(rhs.values.iter())
+                        .map(|(lhs, rhs)| lhs.clone() - rhs.clone())
+                        .collect()
+        }
+    }
+}
+impl<T, const N: usize> Sub for HeaplessVector<T, N>
+where
+    T: Clone + Sub<Output = T> {
+    type Output = HeaplessVector<T, N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<T, const N: usize> Mul for &HeaplessVector<T, N>
+where
+    T: Clone + Default + Add<Output = T> + Mul<Output = T> {
+    type Output = T;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.len() != rhs.len() {
+            panic!("Cannot find dot product of two differently sized vectors.")
+        }
+
+        let mut product = T::default();
+        for idx in 0..self.len() {
+            product = product + self[idx].clone() * rhs[idx].clone()
+        }
+        product
+    }
+}
+impl<T, const N: usize> Mul for HeaplessVector<T, N>
+where
+    T: Clone + Default + Add<Output = T> + Mul<Output = T> {
+    type Output = T;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
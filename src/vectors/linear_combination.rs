@@ -0,0 +1,35 @@
+#![cfg(feature = "full")]
+
+use alloc::vec;
+use core::ops::Mul;
+use num_traits::Zero;
+use crate::vectors::{MutVector, Vector};
+
+/// Computes `weights[0] * vectors[0] + weights[1] * vectors[1] + ...` into a
+/// single reused buffer, without allocating per term.
+///
+/// Panics if `weights` and `vectors` differ in length or if the vectors are not
+/// all the same length. An empty `vectors` produces an empty vector.
+pub fn linear_combination<T>(vectors: &[Vector<T>], weights: &[T]) -> Vector<T>
+where
+    T: Clone + Zero + Mul<Output = T> {
+    if vectors.len() != weights.len() {
+        panic!(
+            "Cannot combine {} vectors with {} weights.",
+            vectors.len(), weights.len()
+        )
+    }
+
+    let length = vectors.first().map_or(0, |vector| vector.len());
+    let mut buffer = MutVector::from(vec![T::zero(); length]);
+    for (vector, weight) in vectors.iter().zip(weights) {
+        if vector.len() != length {
+            panic!("Vectors with different sizes cannot be combined.")
+        }
+
+        buffer.lambda_enumerate_mut(|idx, sum| {
+            *sum = sum.clone() + weight.clone() * vector[idx].clone()
+        });
+    }
+    Vector::from(buffer)
+}
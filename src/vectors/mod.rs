@@ -9,8 +9,13 @@ cfg_if! {
         mod vector;
         mod mut_vector;
 
+        pub use gram_schmidt::gram_schmidt;
+        pub use set_operations::{union, intersection, difference};
+        pub use linear_combination::linear_combination;
+        pub use select::select;
+
         pub struct Vector<T> {
-            values: Vec<T>,
+            pub(crate) values: Vec<T>,
         }
 
         pub struct MutVector<T> {
@@ -19,10 +24,38 @@ cfg_if! {
     }
 }
 
+pub mod traits;
+mod serialize;
+mod gram_schmidt;
+mod set_operations;
+mod linear_combination;
+mod select;
+mod complex;
+pub mod simd;
+pub use traits::VectorType;
+
+cfg_if! {
+    if #[cfg(feature = "num-complex")] {
+        pub use complex::ComplexVectorType;
+    }
+}
+
+cfg_if! {
+    if #[cfg(feature = "heapless")] {
+        mod heapless_vector;
+
+        pub struct HeaplessVector<T, const N: usize> {
+            values: heapless::Vec<T, N>,
+        }
+    }
+}
+
 cfg_if! {
     if #[cfg(feature = "no_std")]  {
         mod vector_slice;
         mod mut_vector_slice;
+        mod array_vector;
+        mod stride_slice;
 
         pub struct VectorSlice<'v, T> {
             values: &'v [T]
@@ -31,6 +64,18 @@ cfg_if! {
         pub struct MutVectorSlice<'v, T> {
             values: &'v mut [T]
         }
+
+        /// A borrowed view of every `stride`-th element of a slice, such as a
+        /// column of a row-major matrix.
+        pub struct StrideSlice<'v, T> {
+            values: &'v [T],
+            stride: usize,
+            len: usize
+        }
+
+        pub struct ArrayVector<T, const N: usize> {
+            values: [T; N]
+        }
     }
 }
 
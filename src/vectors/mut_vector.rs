@@ -1,15 +1,22 @@
 #![cfg(feature = "full")]
 
 use alloc::vec::Vec;
-use crate::vectors::{Vector, MutVector, VectorSlice, MutVectorSlice};
-use core::ops::{Index, IndexMut, Range};
+use crate::vectors::{Vector, MutVector, VectorSlice, MutVectorSlice, VectorType};
+use core::iter::Rev;
+use core::ops::{Add, Index, IndexMut, Mul, Range};
+use core::slice::{Iter, IterMut};
+use num_traits::Float;
 
 impl<T> MutVector<T> {
     pub fn len(&self) -> usize {
         self.values.len()
     }
 
-    pub fn as_slice<'v>(&'v self, range: Range<usize>) -> VectorSlice<'v, T> {
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn as_slice(&self, range: Range<usize>) -> VectorSlice<'_, T> {
         VectorSlice {
             values: self.values
                         .as_slice()
@@ -20,7 +27,7 @@ impl<T> MutVector<T> {
         }
     }
 
-    pub fn as_slice_mut<'v>(&'v mut self, range: Range<usize>) -> MutVectorSlice<'v, T> {
+    pub fn as_slice_mut(&mut self, range: Range<usize>) -> MutVectorSlice<'_, T> {
         MutVectorSlice {
             values: self.values
                         .as_mut_slice()
@@ -32,26 +39,160 @@ impl<T> MutVector<T> {
     }
 }
 impl<T> MutVector<T> {
-    pub fn lambda_mut<'v, F>(&'v mut self, f: F) -> &'v mut Self
+    pub fn iter_rev_mut(&mut self) -> Rev<IterMut<'_, T>> {
+        self.values.iter_mut().rev()
+    }
+
+    pub fn lambda_mut<F>(&mut self, f: F) -> &mut Self
     where
         F: Fn(&mut T) {
-        self.values.iter_mut().for_each(|value| f(value));
+        self.values.iter_mut().for_each(f);
         self
     }
 
-    pub fn lambda_index_mut<'v, F>(&'v mut self, f: F) -> &'v mut Self
+    pub fn lambda_index_mut<F>(&mut self, f: F) -> &mut Self
     where
         F: Fn(usize) {
         self.values.iter_mut().enumerate().for_each(|(index, _)| f(index));
         self
     }
 
-    pub fn lambda_enumerate_mut<'v, F>(&'v mut self, f: F) -> &'v mut Self
+    pub fn lambda_enumerate_mut<F>(&mut self, f: F) -> &mut Self
     where
         F: Fn(usize, &mut T) {
         self.values.iter_mut().enumerate().for_each(|(index, value)| f(index, value));
         self
     }
+
+    /// Normalizes in place unless the norm is below `epsilon` (or `NaN`), in
+    /// which case the vector is left untouched. Returns whether it normalized.
+    pub fn try_normalize_mut(&mut self, epsilon: T) -> bool
+    where
+        T: Float {
+        let norm = self.norm();
+        if norm.is_nan() || norm < epsilon {
+            return false
+        }
+
+        for value in self.values.iter_mut() {
+            *value = *value / norm
+        }
+        true
+    }
+
+    /// In-place softmax, computed stably by subtracting the maximum before
+    /// exponentiating.
+    pub fn softmax_mut(&mut self) -> &mut Self
+    where
+        T: Float {
+        let max = self.values.iter().fold(T::neg_infinity(), |max, value| max.max(*value));
+        let mut sum = T::zero();
+        for value in self.values.iter_mut() {
+            *value = (*value - max).exp();
+            sum = sum + *value
+        }
+        for value in self.values.iter_mut() {
+            *value = *value / sum
+        }
+        self
+    }
+
+    /// Mutates each element in order while threading `state` through, then
+    /// returns the final state.
+    pub fn fold_mut<B, F>(&mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(&mut B, &mut T) {
+        let mut state = init;
+        self.values.iter_mut().for_each(|value| f(&mut state, value));
+        state
+    }
+
+    pub fn copy_from_slice(&mut self, src: &[T]) -> &mut Self
+    where
+        T: Copy {
+        self.values.copy_from_slice(src);
+        self
+    }
+
+    pub fn fill(&mut self, value: T) -> &mut Self
+    where
+        T: Clone {
+        self.values.fill(value);
+        self
+    }
+
+    /// Accumulates `self[i] += a[i] * b[i]` in place. This is a separate multiply
+    /// and add, so floats are rounded twice rather than using a fused `mul_add`.
+    /// Panics if the lengths differ.
+    pub fn fma_mut(&mut self, a: &impl VectorType<T>, b: &impl VectorType<T>) -> &mut Self
+    where
+        T: Clone + Add<Output = T> + Mul<Output = T> {
+        if self.len() != a.len() || self.len() != b.len() {
+            panic!("Vectors with different sizes cannot be multiplied and accumulated.")
+        }
+
+        for ((value, lhs), rhs) in self.values.iter_mut().zip(a.iter()).zip(b.iter()) {
+            *value = value.clone() + lhs.clone() * rhs.clone()
+        }
+        self
+    }
+
+    pub fn retain<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&T) -> bool {
+        self.values.retain(f);
+        self
+    }
+
+    pub fn resize(&mut self, new_len: usize, fill: T) -> &mut Self
+    where
+        T: Clone {
+        self.values.resize(new_len, fill);
+        self
+    }
+
+    /// Removes consecutive repeated elements, as `Vec::dedup` does. Sort the
+    /// vector first to remove every duplicate.
+    pub fn dedup(&mut self) -> &mut Self
+    where
+        T: PartialEq {
+        self.values.dedup();
+        self
+    }
+
+    /// Like `dedup`, but removes each element for which `same(previous, current)`
+    /// holds, where `previous` is the last element kept.
+    pub fn dedup_by<F>(&mut self, mut same: F) -> &mut Self
+    where
+        F: FnMut(&T, &T) -> bool {
+        self.values.dedup_by(|current, previous| same(previous, current));
+        self
+    }
+
+    pub fn insert(&mut self, index: usize, value: T) -> &mut Self {
+        if index > self.values.len() {
+            panic!(
+                "Insertion index {} is out of bounds for a vector of length {}.",
+                index, self.values.len()
+            )
+        }
+
+        self.values.insert(index, value);
+        self
+    }
+
+    /// Removes the element at `index` in O(1) by moving the last element into
+    /// its place, so the order of the remaining elements is not preserved.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        if index >= self.values.len() {
+            panic!(
+                "Removal index {} is out of bounds for a vector of length {}.",
+                index, self.values.len()
+            )
+        }
+
+        self.values.swap_remove(index)
+    }
 }
 impl<T> MutVector<T> {
     pub fn lambda<F>(&self, f: F) -> Vector<T>
@@ -60,7 +201,7 @@ impl<T> MutVector<T> {
         Vector::from(
             self.values
                 .iter()
-                .map(|value| f(value))
+                .map(f)
                 .collect::<Vec<T>>()
         )
     }
@@ -88,8 +229,26 @@ impl<T> MutVector<T> {
                     .collect::<Vec<T>>()
             )
     }
+
+    pub fn filter<F>(&self, f: F) -> Vector<T>
+    where
+        T: Clone,
+        F: Fn(&T) -> bool {
+        Vector::from(
+            self.values
+                .iter()
+                .filter(|value| f(value))
+                .cloned()
+                .collect::<Vec<T>>()
+        )
+    }
 }
 
+impl<T> From<Vector<T>> for MutVector<T> {
+    fn from(vector: Vector<T>) -> Self {
+        MutVector { values: vector.values }
+    }
+}
 impl<T, U> From<U> for MutVector<T>
 where
     U: Into<Vec<T>>
@@ -98,7 +257,7 @@ where
         MutVector { values: values.into() }
     }
 }
-impl<'v, T> Index<usize> for MutVector<T>
+impl<T> Index<usize> for MutVector<T>
 where
     T: Clone,
 {
@@ -108,11 +267,44 @@ where
         &self.values[index]
     }
 }
-impl<'v, T> IndexMut<usize> for MutVector<T>
+impl<T> IndexMut<usize> for MutVector<T>
 where
     T: Clone,
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.values[index]
     }
-}
\ No newline at end of file
+}
+impl<T> VectorType<T> for MutVector<T> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        self.values.iter()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use crate::vectors::{MutVector, VectorType};
+
+    #[test]
+    fn lambda_enumerate_mut_writes_through() {
+        let mut vector = MutVector::from([1, 2, 3]);
+        vector.lambda_enumerate_mut(|index, value| *value *= index as i32);
+
+        assert!(vector.iter().eq([0, 2, 6].iter()));
+    }
+
+    #[test]
+    fn fold_mut_cumsum_writes_through() {
+        let mut vector = MutVector::from([1, 2, 3, 4]);
+        let total = vector.fold_mut(0, |sum, value| {
+            *sum += *value;
+            *value = *sum
+        });
+
+        assert_eq!(total, 10);
+        assert!(vector.iter().eq([1, 3, 6, 10].iter()));
+    }
+}
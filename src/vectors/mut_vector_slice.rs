@@ -1,44 +1,145 @@
 mod no_std {
     #![cfg(feature = "no_std")]
     
-    use core::ops::{Range, Index, IndexMut};
-    use crate::vectors::{VectorSlice, MutVectorSlice};
+    use core::iter::Rev;
+    use core::ops::{Add, Range, Index, IndexMut, Mul};
+    use core::slice::{Iter, IterMut};
+    use num_traits::Float;
+    use crate::vectors::{VectorSlice, MutVectorSlice, VectorType};
 
     impl<'v, T> MutVectorSlice<'v, T> {
         pub fn len(&self) -> usize {
             self.values.len()
         }
 
-        pub fn as_slice(&'v self, range: Range<usize>) -> VectorSlice<'v, T> {
+        pub fn is_empty(&self) -> bool {
+            self.values.is_empty()
+        }
+
+        pub fn as_slice(&self, range: Range<usize>) -> VectorSlice<'_, T> {
             VectorSlice {
                 values: self.values
                             .split_at(range.start).1
                             .split_at(range.len()).0
             }
         }
+
+        pub fn as_slice_mut(&mut self, range: Range<usize>) -> MutVectorSlice<'_, T> {
+            MutVectorSlice {
+                values: self.values
+                            .split_at_mut(range.start).1
+                            .split_at_mut(range.len()).0
+            }
+        }
     }
 
     impl<'v, T> MutVectorSlice<'v, T> {
-        pub fn lambda_mut<F>(&'v mut self, f: F) -> &'v mut Self
+        pub fn iter_rev_mut(&mut self) -> Rev<IterMut<'_, T>> {
+            self.values.iter_mut().rev()
+        }
+
+        pub fn lambda_mut<F>(&mut self, f: F) -> &mut Self
         where
             F: Fn(&mut T) {
-            self.values.iter_mut().for_each(|value| f(value));
+            self.values.iter_mut().for_each(f);
             self
         }
 
-        pub fn lambda_index_mut<F>(&'v mut self, f: F) -> &'v mut Self
+        pub fn lambda_index_mut<F>(&mut self, f: F) -> &mut Self
         where
             F: Fn(usize) {
             self.values.iter_mut().enumerate().for_each(|(index, _)| f(index));
             self
         }
 
-        pub fn lambda_enumerate_mut<F>(&'v mut self, f: F) -> &'v mut Self
+        pub fn lambda_enumerate_mut<F>(&mut self, f: F) -> &mut Self
         where
             F: Fn(usize, &mut T) {
             self.values.iter_mut().enumerate().for_each(|(index, value)| f(index, value));
             self
         }
+
+        /// Normalizes in place unless the norm is below `epsilon` (or `NaN`), in
+        /// which case the vector is left untouched. Returns whether it normalized.
+        pub fn try_normalize_mut(&mut self, epsilon: T) -> bool
+        where
+            T: Float {
+            let norm = self.norm();
+            if norm.is_nan() || norm < epsilon {
+                return false
+            }
+
+            for value in self.values.iter_mut() {
+                *value = *value / norm
+            }
+            true
+        }
+
+        /// In-place softmax, computed stably by subtracting the maximum before
+        /// exponentiating.
+        pub fn softmax_mut(&mut self) -> &mut Self
+        where
+            T: Float {
+            let max = self.values.iter().fold(T::neg_infinity(), |max, value| max.max(*value));
+            let mut sum = T::zero();
+            for value in self.values.iter_mut() {
+                *value = (*value - max).exp();
+                sum = sum + *value
+            }
+            for value in self.values.iter_mut() {
+                *value = *value / sum
+            }
+            self
+        }
+
+        /// Mutates each element in order while threading `state` through, then
+        /// returns the final state.
+        pub fn fold_mut<B, F>(&mut self, init: B, mut f: F) -> B
+        where
+            F: FnMut(&mut B, &mut T) {
+            let mut state = init;
+            self.values.iter_mut().for_each(|value| f(&mut state, value));
+            state
+        }
+
+        pub fn copy_from_slice(&mut self, src: &[T]) -> &mut Self
+        where
+            T: Copy {
+            self.values.copy_from_slice(src);
+            self
+        }
+
+        pub fn fill(&mut self, value: T) -> &mut Self
+        where
+            T: Clone {
+            self.values.fill(value);
+            self
+        }
+
+        /// Accumulates `self[i] += a[i] * b[i]` in place. This is a separate multiply
+        /// and add, so floats are rounded twice rather than using a fused `mul_add`.
+        /// Panics if the lengths differ.
+        pub fn fma_mut(&mut self, a: &impl VectorType<T>, b: &impl VectorType<T>) -> &mut Self
+        where
+            T: Clone + Add<Output = T> + Mul<Output = T> {
+            if self.len() != a.len() || self.len() != b.len() {
+                panic!("Vectors with different sizes cannot be multiplied and accumulated.")
+            }
+
+            for ((value, lhs), rhs) in self.values.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *value = value.clone() + lhs.clone() * rhs.clone()
+            }
+            self
+        }
+
+        pub fn swap_with(&mut self, other: &mut MutVectorSlice<'_, T>) -> &mut Self {
+            if self.len() != other.len() {
+                panic!("Vectors with different sizes cannot be swapped.")
+            }
+
+            self.values.swap_with_slice(other.values);
+            self
+        }
     }
 
     impl<'v, T, U> From<U> for MutVectorSlice<'v, T>
@@ -66,6 +167,15 @@ mod no_std {
             &mut self.values[index]
         }
     }
+    impl<'v, T> VectorType<T> for MutVectorSlice<'v, T> {
+        fn len(&self) -> usize {
+            self.values.len()
+        }
+
+        fn iter(&self) -> Iter<'_, T> {
+            self.values.iter()
+        }
+    }
 }
 
 mod full {
@@ -81,7 +191,7 @@ mod full {
             Vector::from(
                 self.values
                     .iter()
-                    .map(|value| f(value))
+                    .map(f)
                     .collect::<Vec<T>>()
             )
         }
@@ -109,5 +219,44 @@ mod full {
                         .collect::<Vec<T>>()
                 )
         }
+
+        pub fn filter<F>(&self, f: F) -> Vector<T>
+        where
+            T: Clone,
+            F: Fn(&T) -> bool {
+            Vector::from(
+                self.values
+                    .iter()
+                    .filter(|value| f(value))
+                    .cloned()
+                    .collect::<Vec<T>>()
+            )
+        }
     }
-}
\ No newline at end of file
+}
+#[cfg(all(test, feature = "no_std"))]
+mod tests {
+    use crate::vectors::{MutVectorSlice, VectorType};
+
+    #[test]
+    fn lambda_enumerate_mut_writes_through() {
+        let mut raw = [1, 2, 3];
+        MutVectorSlice::from(&mut raw[..]).lambda_enumerate_mut(|index, value| *value *= index as i32);
+
+        assert_eq!(raw, [0, 2, 6]);
+    }
+
+    #[test]
+    fn fold_mut_cumsum_writes_through() {
+        let mut raw = [1, 2, 3, 4];
+        let mut slice = MutVectorSlice::from(&mut raw[1..]);
+        let total = slice.fold_mut(0, |sum, value| {
+            *sum += *value;
+            *value = *sum
+        });
+
+        assert_eq!(total, 9);
+        assert!(slice.iter().eq([2, 5, 9].iter()));
+        assert_eq!(raw, [1, 2, 5, 9]);
+    }
+}
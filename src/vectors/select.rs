@@ -0,0 +1,25 @@
+#![cfg(feature = "full")]
+
+use alloc::vec::Vec;
+use crate::vectors::{Vector, VectorType};
+
+/// Picks `if_true[i]` where `mask[i]` is set and `if_false[i]` elsewhere, like
+/// `numpy.where`. Panics unless all three inputs have the same length.
+pub fn select<T>(
+    mask: &impl VectorType<bool>,
+    if_true: &impl VectorType<T>,
+    if_false: &impl VectorType<T>
+) -> Vector<T>
+where
+    T: Clone {
+    if mask.len() != if_true.len() || mask.len() != if_false.len() {
+        panic!("Vectors with different sizes cannot be selected between.")
+    }
+
+    Vector::from(
+        mask.iter()
+            .zip(if_true.iter().zip(if_false.iter()))
+            .map(|(&choice, (lhs, rhs))| if choice { lhs.clone() } else { rhs.clone() })
+            .collect::<Vec<T>>()
+    )
+}
@@ -0,0 +1,211 @@
+#![cfg(feature = "serde")]
+
+mod no_std {
+    #![cfg(feature = "no_std")]
+
+    use core::array;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde::de::{Error, IgnoredAny, SeqAccess, Visitor};
+    use crate::vectors::{ArrayVector, VectorSlice, MutVectorSlice, StrideSlice};
+
+    impl<'v, T> Serialize for VectorSlice<'v, T>
+    where
+        T: Serialize {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer {
+            serializer.collect_seq(self.values.iter())
+        }
+    }
+
+    impl<'v, T> Serialize for MutVectorSlice<'v, T>
+    where
+        T: Serialize {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer {
+            serializer.collect_seq(self.values.iter())
+        }
+    }
+
+    impl<'v, T> Serialize for StrideSlice<'v, T>
+    where
+        T: Serialize {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer {
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    impl<T, const N: usize> Serialize for ArrayVector<T, N>
+    where
+        T: Serialize {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer {
+            serializer.collect_seq(self.values.iter())
+        }
+    }
+
+    struct ArrayVectorVisitor<T, const N: usize>(PhantomData<T>);
+
+    impl<'de, T, const N: usize> Visitor<'de> for ArrayVectorVisitor<T, N>
+    where
+        T: Deserialize<'de> {
+        type Value = ArrayVector<T, N>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a sequence of exactly {} elements", N)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de> {
+            // fill a buffer of options first, since `T` need not have a default
+            let mut values: [Option<T>; N] = array::from_fn(|_| None);
+            for (idx, slot) in values.iter_mut().enumerate() {
+                match seq.next_element()? {
+                    Some(value) => *slot = Some(value),
+                    None => return Err(A::Error::invalid_length(idx, &self))
+                }
+            }
+            if seq.next_element::<IgnoredAny>()?.is_some() {
+                return Err(A::Error::invalid_length(N + 1, &self))
+            }
+
+            Ok(ArrayVector::from(values.map(|value| value.unwrap())))
+        }
+    }
+
+    impl<'de, T, const N: usize> Deserialize<'de> for ArrayVector<T, N>
+    where
+        T: Deserialize<'de> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de> {
+            deserializer.deserialize_seq(ArrayVectorVisitor(PhantomData))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::vectors::{ArrayVector, VectorType};
+
+        #[test]
+        fn array_round_trip() {
+            let vector = ArrayVector::from([1, -2, 3]);
+
+            let json = serde_json::to_string(&vector).unwrap();
+            assert_eq!(json, "[1,-2,3]");
+
+            let parsed: ArrayVector<i32, 3> = serde_json::from_str(&json).unwrap();
+            assert!(parsed.iter().eq(vector.iter()));
+        }
+
+        #[test]
+        fn array_length_must_match() {
+            assert!(serde_json::from_str::<ArrayVector<i32, 3>>("[1,2]").is_err());
+            assert!(serde_json::from_str::<ArrayVector<i32, 3>>("[1,2,3,4]").is_err());
+        }
+    }
+}
+
+mod heapless {
+    #![cfg(feature = "heapless")]
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use crate::vectors::HeaplessVector;
+
+    impl<T, const N: usize> Serialize for HeaplessVector<T, N>
+    where
+        T: Serialize {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer {
+            serializer.collect_seq(self.values.iter())
+        }
+    }
+
+    impl<'de, T, const N: usize> Deserialize<'de> for HeaplessVector<T, N>
+    where
+        T: Deserialize<'de> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de> {
+            ::heapless::Vec::<T, N>::deserialize(deserializer).map(HeaplessVector::from)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::vectors::{HeaplessVector, VectorType};
+
+        #[test]
+        fn round_trip() {
+            let mut vector = HeaplessVector::<i32, 4>::new();
+            vector.push(1).unwrap().push(-2).unwrap().push(3).unwrap();
+
+            let json = serde_json::to_string(&vector).unwrap();
+            assert_eq!(json, "[1,-2,3]");
+
+            let parsed: HeaplessVector<i32, 4> = serde_json::from_str(&json).unwrap();
+            assert!(parsed.iter().eq(vector.iter()));
+        }
+
+        #[test]
+        fn over_capacity_is_rejected() {
+            assert!(serde_json::from_str::<HeaplessVector<i32, 2>>("[1,2,3]").is_err());
+        }
+    }
+}
+
+mod full {
+    #![cfg(feature = "full")]
+
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use crate::vectors::{Vector, MutVector};
+
+    impl<T> Serialize for Vector<T>
+    where
+        T: Serialize {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer {
+            serializer.collect_seq(self.values.iter())
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for Vector<T>
+    where
+        T: Deserialize<'de> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de> {
+            Vec::<T>::deserialize(deserializer).map(Vector::from)
+        }
+    }
+
+    impl<T> Serialize for MutVector<T>
+    where
+        T: Serialize {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer {
+            serializer.collect_seq(self.values.iter())
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for MutVector<T>
+    where
+        T: Deserialize<'de> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de> {
+            Vec::<T>::deserialize(deserializer).map(MutVector::from)
+        }
+    }
+}
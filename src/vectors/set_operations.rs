@@ -0,0 +1,125 @@
+#![cfg(feature = "full")]
+
+//! Set algebra over sorted vectors.
+//!
+//! Every function here requires both inputs to be sorted in strictly ascending
+//! order, i.e. to be sets with no duplicates. This is checked with a debug
+//! assertion; in release builds other inputs give meaningless results.
+//! Incomparable elements (such as `NaN`) are never treated as equal. Each
+//! function runs in `O(n + m)`.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use crate::vectors::{Vector, VectorType};
+
+fn is_strictly_sorted<T>(values: &impl VectorType<T>) -> bool
+where
+    T: PartialOrd {
+    let values = values.iter().as_slice();
+    values.windows(2).all(|pair| pair[0] < pair[1])
+}
+
+fn merge<T>(
+    lhs: &impl VectorType<T>,
+    rhs: &impl VectorType<T>,
+    keep_lhs: bool,
+    keep_both: bool,
+    keep_rhs: bool
+) -> Vector<T>
+where
+    T: Clone + PartialOrd {
+    debug_assert!(is_strictly_sorted(lhs), "Set operations require the left vector to be sorted without duplicates.");
+    debug_assert!(is_strictly_sorted(rhs), "Set operations require the right vector to be sorted without duplicates.");
+
+    let lhs = lhs.iter().as_slice();
+    let rhs = rhs.iter().as_slice();
+
+    let mut values = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < lhs.len() && j < rhs.len() {
+        match lhs[i].partial_cmp(&rhs[j]) {
+            Some(Ordering::Less) => {
+                if keep_lhs {
+                    values.push(lhs[i].clone())
+                }
+                i += 1
+            },
+            Some(Ordering::Greater) => {
+                if keep_rhs {
+                    values.push(rhs[j].clone())
+                }
+                j += 1
+            },
+            Some(Ordering::Equal) => {
+                if keep_both {
+                    values.push(lhs[i].clone())
+                }
+                i += 1;
+                j += 1
+            },
+            None => {
+                if keep_lhs {
+                    values.push(lhs[i].clone())
+                }
+                if keep_rhs {
+                    values.push(rhs[j].clone())
+                }
+                i += 1;
+                j += 1
+            }
+        }
+    }
+
+    if keep_lhs {
+        values.extend_from_slice(&lhs[i..])
+    }
+    if keep_rhs {
+        values.extend_from_slice(&rhs[j..])
+    }
+    Vector::from(values)
+}
+
+/// Elements in either sorted vector.
+pub fn union<T>(lhs: &impl VectorType<T>, rhs: &impl VectorType<T>) -> Vector<T>
+where
+    T: Clone + PartialOrd {
+    merge(lhs, rhs, true, true, true)
+}
+
+/// Elements in both sorted vectors.
+pub fn intersection<T>(lhs: &impl VectorType<T>, rhs: &impl VectorType<T>) -> Vector<T>
+where
+    T: Clone + PartialOrd {
+    merge(lhs, rhs, false, true, false)
+}
+
+/// Elements of the sorted `lhs` that are not in the sorted `rhs`.
+pub fn difference<T>(lhs: &impl VectorType<T>, rhs: &impl VectorType<T>) -> Vector<T>
+where
+    T: Clone + PartialOrd {
+    merge(lhs, rhs, true, false, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vector;
+    use crate::vectors::VectorType;
+    use super::{union, intersection, difference};
+
+    #[test]
+    fn sorted_sets() {
+        let lhs = vector![1, 3, 5];
+        let rhs = vector![3, 4];
+
+        assert!(union(&lhs, &rhs).iter().eq([1, 3, 4, 5].iter()));
+        assert!(intersection(&lhs, &rhs).iter().eq([3].iter()));
+        assert!(difference(&lhs, &rhs).iter().eq([1, 5].iter()));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "without duplicates")]
+    fn duplicates_are_rejected() {
+        union(&vector![1, 1], &vector![2]);
+    }
+}
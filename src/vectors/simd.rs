@@ -0,0 +1,199 @@
+#![cfg(feature = "runtime-simd")]
+//! Dot products that pick a SIMD kernel at runtime based on the running CPU,
+//! so portable binaries get the speedup without `target-feature` flags.
+//!
+//! On `x86_64` an AVX kernel is used when available, otherwise SSE2 (which
+//! every `x86_64` CPU has). Other targets fall back to the scalar loop. The
+//! summation order differs from `VectorType::dot`, so results may differ in
+//! the last few bits.
+
+use crate::vectors::VectorType;
+
+pub fn dot_f32(lhs: &impl VectorType<f32>, rhs: &impl VectorType<f32>) -> f32 {
+    if lhs.len() != rhs.len() {
+        panic!("Cannot find dot product of two differently sized vectors.")
+    }
+
+    let (lhs, rhs) = (lhs.iter().as_slice(), rhs.iter().as_slice());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx") {
+            // SAFETY: AVX support was just checked.
+            return unsafe { x86_64::dot_f32_avx(lhs, rhs) }
+        }
+        // SAFETY: SSE2 is part of the x86_64 baseline.
+        unsafe { x86_64::dot_f32_sse2(lhs, rhs) }
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        lhs.dot(&rhs)
+    }
+}
+
+pub fn dot_f64(lhs: &impl VectorType<f64>, rhs: &impl VectorType<f64>) -> f64 {
+    if lhs.len() != rhs.len() {
+        panic!("Cannot find dot product of two differently sized vectors.")
+    }
+
+    let (lhs, rhs) = (lhs.iter().as_slice(), rhs.iter().as_slice());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx") {
+            // SAFETY: AVX support was just checked.
+            return unsafe { x86_64::dot_f64_avx(lhs, rhs) }
+        }
+        // SAFETY: SSE2 is part of the x86_64 baseline.
+        unsafe { x86_64::dot_f64_sse2(lhs, rhs) }
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        lhs.dot(&rhs)
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+    use core::arch::x86_64::*;
+
+    /// Sums the lanes of `sums` and adds the products left over after the last
+    /// full block.
+    fn finish<T>(sums: &[T], lhs: &[T], rhs: &[T], done: usize) -> T
+    where
+        T: Copy + core::iter::Sum + core::ops::Add<Output = T> + core::ops::Mul<Output = T> {
+        sums.iter().copied().sum::<T>()
+            + lhs[done..].iter()
+                .zip(&rhs[done..])
+                .map(|(lhs, rhs)| *lhs * *rhs)
+                .sum::<T>()
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn dot_f32_avx(lhs: &[f32], rhs: &[f32]) -> f32 {
+        let blocks = lhs.len() / 8;
+        let mut acc = _mm256_setzero_ps();
+        for block in 0..blocks {
+            let a = _mm256_loadu_ps(lhs.as_ptr().add(block * 8));
+            let b = _mm256_loadu_ps(rhs.as_ptr().add(block * 8));
+            acc = _mm256_add_ps(acc, _mm256_mul_ps(a, b));
+        }
+
+        let mut sums = [0.0f32; 8];
+        _mm256_storeu_ps(sums.as_mut_ptr(), acc);
+        finish(&sums, lhs, rhs, blocks * 8)
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn dot_f32_sse2(lhs: &[f32], rhs: &[f32]) -> f32 {
+        let blocks = lhs.len() / 4;
+        let mut acc = _mm_setzero_ps();
+        for block in 0..blocks {
+            let a = _mm_loadu_ps(lhs.as_ptr().add(block * 4));
+            let b = _mm_loadu_ps(rhs.as_ptr().add(block * 4));
+            acc = _mm_add_ps(acc, _mm_mul_ps(a, b));
+        }
+
+        let mut sums = [0.0f32; 4];
+        _mm_storeu_ps(sums.as_mut_ptr(), acc);
+        finish(&sums, lhs, rhs, blocks * 4)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn dot_f64_avx(lhs: &[f64], rhs: &[f64]) -> f64 {
+        let blocks = lhs.len() / 4;
+        let mut acc = _mm256_setzero_pd();
+        for block in 0..blocks {
+            let a = _mm256_loadu_pd(lhs.as_ptr().add(block * 4));
+            let b = _mm256_loadu_pd(rhs.as_ptr().add(block * 4));
+            acc = _mm256_add_pd(acc, _mm256_mul_pd(a, b));
+        }
+
+        let mut sums = [0.0f64; 4];
+        _mm256_storeu_pd(sums.as_mut_ptr(), acc);
+        finish(&sums, lhs, rhs, blocks * 4)
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn dot_f64_sse2(lhs: &[f64], rhs: &[f64]) -> f64 {
+        let blocks = lhs.len() / 2;
+        let mut acc = _mm_setzero_pd();
+        for block in 0..blocks {
+            let a = _mm_loadu_pd(lhs.as_ptr().add(block * 2));
+            let b = _mm_loadu_pd(rhs.as_ptr().add(block * 2));
+            acc = _mm_add_pd(acc, _mm_mul_pd(a, b));
+        }
+
+        let mut sums = [0.0f64; 2];
+        _mm_storeu_pd(sums.as_mut_ptr(), acc);
+        finish(&sums, lhs, rhs, blocks * 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dot_f32, dot_f64};
+    use crate::vectors::VectorType;
+
+    const LENGTHS: [usize; 7] = [0, 1, 7, 8, 9, 17, 100];
+
+    #[test]
+    fn dot_f32_matches_scalar() {
+        for len in LENGTHS {
+            let lhs = (0..len).map(|idx| idx as f32 * 0.5).collect::<std::vec::Vec<f32>>();
+            let rhs = (0..len).map(|idx| 1.0 - idx as f32 * 0.25).collect::<std::vec::Vec<f32>>();
+            let (lhs, rhs) = (lhs.as_slice(), rhs.as_slice());
+
+            let expected = lhs.dot(&rhs);
+            let actual = dot_f32(&lhs, &rhs);
+            assert!((actual - expected).abs() <= 1e-4 * expected.abs().max(1.0), "length {}", len);
+        }
+    }
+
+    #[test]
+    fn dot_f64_matches_scalar() {
+        for len in LENGTHS {
+            let lhs = (0..len).map(|idx| idx as f64 * 0.5).collect::<std::vec::Vec<f64>>();
+            let rhs = (0..len).map(|idx| 1.0 - idx as f64 * 0.25).collect::<std::vec::Vec<f64>>();
+            let (lhs, rhs) = (lhs.as_slice(), rhs.as_slice());
+
+            let expected = lhs.dot(&rhs);
+            let actual = dot_f64(&lhs, &rhs);
+            assert!((actual - expected).abs() <= 1e-12 * expected.abs().max(1.0), "length {}", len);
+        }
+    }
+
+    /// `dot_f32`/`dot_f64` only reach one kernel per machine, so check each
+    /// kernel directly as well.
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn x86_64_kernels_match_scalar() {
+        use super::x86_64::*;
+
+        let avx = std::is_x86_feature_detected!("avx");
+        for len in LENGTHS {
+            let lhs = (0..len).map(|idx| idx as f64 * 0.5).collect::<std::vec::Vec<f64>>();
+            let rhs = (0..len).map(|idx| 1.0 - idx as f64 * 0.25).collect::<std::vec::Vec<f64>>();
+            let expected = lhs.as_slice().dot(&rhs.as_slice());
+            let close = |actual: f64, tolerance: f64| (actual - expected).abs() <= tolerance * expected.abs().max(1.0);
+
+            let (lhs_f32, rhs_f32) = (
+                lhs.iter().map(|value| *value as f32).collect::<std::vec::Vec<f32>>(),
+                rhs.iter().map(|value| *value as f32).collect::<std::vec::Vec<f32>>()
+            );
+
+            // SAFETY: SSE2 is part of the x86_64 baseline, and AVX is only used
+            // when detected.
+            unsafe {
+                assert!(close(dot_f64_sse2(&lhs, &rhs), 1e-12), "sse2 f64, length {}", len);
+                assert!(close(dot_f32_sse2(&lhs_f32, &rhs_f32) as f64, 1e-4), "sse2 f32, length {}", len);
+                if avx {
+                    assert!(close(dot_f64_avx(&lhs, &rhs), 1e-12), "avx f64, length {}", len);
+                    assert!(close(dot_f32_avx(&lhs_f32, &rhs_f32) as f64, 1e-4), "avx f32, length {}", len);
+                }
+            }
+        }
+    }
+}
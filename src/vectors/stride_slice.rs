@@ -0,0 +1,152 @@
+mod no_std {
+    #![cfg(feature = "no_std")]
+
+    use core::iter::{StepBy, Take};
+    use core::ops::{Index, Mul};
+    use core::slice::Iter;
+    use num_traits::{Float, Zero};
+    use crate::vectors::{StrideSlice, VectorType};
+
+    impl<'v, T> StrideSlice<'v, T> {
+        /// A view of `len` elements of `values`, taking every `stride`-th one
+        /// starting from the first. Panics if `stride` is zero or the view
+        /// would run past the end of `values`.
+        pub fn new(values: &'v [T], stride: usize, len: usize) -> Self {
+            if stride == 0 || (len > 0 && (len - 1) * stride >= values.len()) {
+                panic!(
+                    "Cannot view {} elements with stride {} in a slice of length {}.",
+                    len, stride, values.len()
+                )
+            }
+
+            StrideSlice { values, stride, len }
+        }
+
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        pub fn get(&self, index: usize) -> Option<&T> {
+            if index < self.len {
+                self.values.get(index * self.stride)
+            } else {
+                None
+            }
+        }
+
+        pub fn iter(&self) -> Take<StepBy<Iter<'v, T>>> {
+            self.values.iter().step_by(self.stride).take(self.len)
+        }
+
+        pub fn sum(&self) -> T
+        where
+            T: Clone + Zero {
+            self.iter().fold(T::zero(), |sum, value| sum + value.clone())
+        }
+
+        /// Dot product with any vector of the same length. Panics if the
+        /// lengths differ.
+        pub fn dot(&self, other: &impl VectorType<T>) -> T
+        where
+            T: Clone + Zero + Mul<Output = T> {
+            if self.len != other.len() {
+                panic!("Cannot find dot product of two differently sized vectors.")
+            }
+
+            self.iter()
+                .zip(other.iter())
+                .fold(T::zero(), |sum, (lhs, rhs)| sum + lhs.clone() * rhs.clone())
+        }
+
+        pub fn norm_squared(&self) -> T
+        where
+            T: Clone + Zero + Mul<Output = T> {
+            self.iter()
+                .fold(T::zero(), |sum, value| sum + value.clone() * value.clone())
+        }
+
+        pub fn norm(&self) -> T
+        where
+            T: Float {
+            self.norm_squared().sqrt()
+        }
+    }
+    impl<'v, T> Index<usize> for StrideSlice<'v, T> {
+        type Output = T;
+
+        fn index(&self, index: usize) -> &Self::Output {
+            if index >= self.len {
+                panic!("Index {} is out of bounds for a strided slice of length {}.", index, self.len)
+            }
+
+            &self.values[index * self.stride]
+        }
+    }
+}
+
+mod full {
+    #![cfg(feature = "full")]
+
+    use alloc::vec::Vec;
+    use crate::vectors::{StrideSlice, Vector};
+
+    impl<'v, T> StrideSlice<'v, T> {
+        /// Copies the viewed elements into a contiguous `Vector`, which has the
+        /// full vector API.
+        pub fn to_vector(&self) -> Vector<T>
+        where
+            T: Clone {
+            Vector::from(self.iter().cloned().collect::<Vec<T>>())
+        }
+    }
+    impl<'v, T> From<StrideSlice<'v, T>> for Vector<T>
+    where
+        T: Clone {
+        fn from(vector: StrideSlice<'v, T>) -> Self {
+            vector.to_vector()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "full"))]
+mod tests {
+    use crate::matrix;
+    use crate::matricies::Matrix;
+    use crate::vectors::VectorType;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn column_view_borrows_strided_elements() {
+        let matrix: Matrix<i32> = matrix![[1, 2, 3], [4, 5, 6]];
+        let col = matrix.col(1);
+
+        assert_eq!(col.len(), 2);
+        assert_eq!((col[0], col[1]), (2, 5));
+        assert_eq!(col.get(2), None);
+        assert!(col.iter().eq([2, 5].iter()));
+        assert!(col.to_vector().iter().eq([2, 5].iter()));
+    }
+
+    #[test]
+    fn iter_cols_covers_every_column() {
+        let matrix: Matrix<i32> = matrix![[1, 2], [3, 4], [5, 6]];
+        let sums = matrix.iter_cols().map(|col| col.iter().sum::<i32>()).collect::<Vec<i32>>();
+
+        assert_eq!(sums, [9, 12]);
+    }
+
+    #[test]
+    fn column_reductions() {
+        let matrix: Matrix<f64> = matrix![[3.0, 1.0], [4.0, 2.0]];
+        let col = matrix.col(0);
+
+        assert_eq!(col.sum(), 7.0);
+        assert_eq!(col.norm_squared(), 25.0);
+        assert_eq!(col.norm(), 5.0);
+        assert_eq!(col.dot(&matrix.row(1)), 20.0);
+    }
+}
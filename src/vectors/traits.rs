@@ -0,0 +1,989 @@
+//! Traits shared by every vector type. Implement `VectorType` for your own
+//! storage to get the default operations, or use it as a bound in generic code.
+
+use core::iter::Rev;
+use core::ops::{Add, Mul};
+use core::slice::Iter;
+use cfg_if::cfg_if;
+use num_traits::{CheckedAdd, CheckedMul, Float, Zero};
+
+cfg_if! {
+    if #[cfg(feature = "full")] {
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::cmp::Ordering;
+        use core::ops::Div;
+        use num_traits::One;
+        use crate::errors::{ChunkError, SplitError};
+        use crate::vectors::{Vector, MutVector};
+    }
+}
+
+cfg_if! {
+    if #[cfg(feature = "no_std")] {
+        use crate::vectors::VectorSlice;
+    }
+}
+
+pub trait VectorType<T> {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn iter(&self) -> Iter<'_, T>;
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.iter().as_slice().get(index)
+    }
+
+    /// # Safety
+    /// `index` must be less than `self.iter().len()`. For the types in this
+    /// crate that is the same as `self.len()`, but the trait is not sealed and
+    /// nothing forces other implementations to keep the two consistent.
+    unsafe fn get_unchecked(&self, index: usize) -> &T {
+        self.iter().as_slice().get_unchecked(index)
+    }
+
+    /// Iterates back-to-front. The iterator is double-ended and exact-size;
+    /// to keep original indices, use `self.iter().enumerate().rev()`.
+    fn iter_rev(&self) -> Rev<Iter<'_, T>> {
+        self.iter().rev()
+    }
+
+    /// Passes the vector to `f`, so custom steps can be chained like methods.
+    fn apply<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnOnce(Self) -> R {
+        f(self)
+    }
+
+    /// Like `apply`, but borrows the vector.
+    fn apply_ref<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&Self) -> R {
+        f(self)
+    }
+
+    /// Discrete linear convolution of `self` with `kernel`.
+    ///
+    /// The output has length `self.len() + kernel.len() - 1`, or is empty if
+    /// either input is empty.
+    #[cfg(feature = "full")]
+    fn convolve(&self, kernel: &impl VectorType<T>) -> Vector<T>
+    where
+        T: Clone + Zero + Add<Output = T> + Mul<Output = T> {
+        if self.is_empty() || kernel.is_empty() {
+            return Vector::from(vec![])
+        }
+
+        let mut values = vec![T::zero(); self.len() + kernel.len() - 1];
+        for (i, lhs) in self.iter().enumerate() {
+            for (j, rhs) in kernel.iter().enumerate() {
+                values[i + j] = values[i + j].clone() + lhs.clone() * rhs.clone()
+            }
+        }
+        Vector::from(values)
+    }
+
+    /// Discrete cross-correlation of `self` with `kernel`.
+    ///
+    /// This is `convolve` without flipping the kernel, so the output has the
+    /// same length as `convolve` would produce.
+    #[cfg(feature = "full")]
+    fn correlate(&self, kernel: &impl VectorType<T>) -> Vector<T>
+    where
+        T: Clone + Zero + Add<Output = T> + Mul<Output = T> {
+        if self.is_empty() || kernel.is_empty() {
+            return Vector::from(vec![])
+        }
+
+        let last = kernel.len() - 1;
+        let mut values = vec![T::zero(); self.len() + last];
+        for (i, lhs) in self.iter().enumerate() {
+            for (j, rhs) in kernel.iter().enumerate() {
+                values[i + last - j] = values[i + last - j].clone() + lhs.clone() * rhs.clone()
+            }
+        }
+        Vector::from(values)
+    }
+
+    /// Evaluates the vector as a polynomial at `x`, using Horner's method.
+    ///
+    /// Coefficients are ordered lowest-degree first, so `[a, b, c]` is
+    /// `a + b*x + c*x^2`. An empty vector evaluates to zero.
+    fn eval_poly(&self, x: T) -> T
+    where
+        T: Clone + Zero + Add<Output = T> + Mul<Output = T> {
+        self.iter()
+            .rev()
+            .fold(T::zero(), |acc, coefficient| acc * x.clone() + coefficient.clone())
+    }
+
+    /// Coefficients of the derivative of the polynomial described by `self`
+    /// (lowest-degree first, as in `eval_poly`).
+    #[cfg(feature = "full")]
+    fn derivative(&self) -> Vector<T>
+    where
+        T: Clone + One + Add<Output = T> + Mul<Output = T> {
+        let mut degree = T::one();
+        let mut values = Vec::with_capacity(self.len().saturating_sub(1));
+        for coefficient in self.iter().skip(1) {
+            values.push(coefficient.clone() * degree.clone());
+            degree = degree + T::one();
+        }
+        Vector::from(values)
+    }
+
+    /// Statically dispatched dot product, for use in generic code without going
+    /// through the `*` operator impls. Panics if the lengths differ.
+    fn dot(&self, other: &impl VectorType<T>) -> T
+    where
+        T: Clone + Zero + Mul<Output = T> {
+        if self.len() != other.len() {
+            panic!("Cannot find dot product of two differently sized vectors.")
+        }
+
+        self.iter()
+            .zip(other.iter())
+            .fold(T::zero(), |sum, (lhs, rhs)| sum + lhs.clone() * rhs.clone())
+    }
+
+    /// Dot product that skips the multiplication wherever either element is
+    /// zero. Faster than `dot` on mostly-zero data, slower on dense data where
+    /// the extra comparisons never pay off. Panics if the lengths differ.
+    fn dot_sparse(&self, other: &impl VectorType<T>) -> T
+    where
+        T: Clone + Zero + Mul<Output = T> {
+        if self.len() != other.len() {
+            panic!("Cannot find dot product of two differently sized vectors.")
+        }
+
+        self.iter()
+            .zip(other.iter())
+            .filter(|(lhs, rhs)| !lhs.is_zero() && !rhs.is_zero())
+            .fold(T::zero(), |sum, (lhs, rhs)| sum + lhs.clone() * rhs.clone())
+    }
+
+    /// Dot product that returns `None` if any product or partial sum overflows
+    /// `T`, instead of silently wrapping. Panics if the lengths differ.
+    fn checked_dot(&self, other: &impl VectorType<T>) -> Option<T>
+    where
+        T: Zero + CheckedAdd + CheckedMul {
+        if self.len() != other.len() {
+            panic!("Cannot find dot product of two differently sized vectors.")
+        }
+
+        self.iter()
+            .zip(other.iter())
+            .try_fold(T::zero(), |sum, (lhs, rhs)| sum.checked_add(&lhs.checked_mul(rhs)?))
+    }
+
+    /// Inner product under a diagonal metric, `sum(w[i] * self[i] * other[i])`.
+    /// Panics if the lengths differ.
+    fn weighted_dot(&self, other: &impl VectorType<T>, weights: &impl VectorType<T>) -> T
+    where
+        T: Clone + Zero + Mul<Output = T> {
+        if self.len() != other.len() || self.len() != weights.len() {
+            panic!("Cannot find dot product of two differently sized vectors.")
+        }
+
+        self.iter()
+            .zip(other.iter())
+            .zip(weights.iter())
+            .fold(T::zero(), |sum, ((lhs, rhs), weight)| sum + weight.clone() * lhs.clone() * rhs.clone())
+    }
+
+    /// Overwrites `*out` with the dot product, so results can be written
+    /// straight into a preallocated buffer. Panics if the lengths differ.
+    fn dot_into_slot(&self, other: &impl VectorType<T>, out: &mut T)
+    where
+        T: Clone + Zero + Mul<Output = T> {
+        *out = self.dot(other)
+    }
+
+    /// Running partial dot products, so `out[i]` is the dot product of
+    /// `self[..=i]` and `other[..=i]`. Panics if the lengths differ.
+    #[cfg(feature = "full")]
+    fn prefix_dot(&self, other: &impl VectorType<T>) -> Vector<T>
+    where
+        T: Clone + Add<Output = T> + Mul<Output = T> {
+        if self.len() != other.len() {
+            panic!("Cannot find dot product of two differently sized vectors.")
+        }
+
+        let mut sums: Vec<T> = Vec::with_capacity(self.len());
+        for (lhs, rhs) in self.iter().zip(other.iter()) {
+            let product = lhs.clone() * rhs.clone();
+            let next = match sums.last() {
+                Some(sum) => sum.clone() + product,
+                None => product
+            };
+            sums.push(next)
+        }
+        Vector::from(sums)
+    }
+
+    /// Sum of the squared elements. Unlike `norm`, this needs no square root
+    /// and so also works for integer vectors.
+    fn norm_squared(&self) -> T
+    where
+        T: Clone + Zero + Mul<Output = T> {
+        self.iter()
+            .fold(T::zero(), |sum, value| sum + value.clone() * value.clone())
+    }
+
+    fn norm(&self) -> T
+    where
+        T: Float {
+        self.norm_squared().sqrt()
+    }
+
+    /// Number of non-zero elements, also known as the L0 "norm".
+    fn count_nonzero(&self) -> usize
+    where
+        T: Zero {
+        self.iter().filter(|value| !value.is_zero()).count()
+    }
+
+    /// Fraction of elements that are zero. An empty vector has sparsity `0.0`.
+    fn sparsity(&self) -> f64
+    where
+        T: Zero {
+        if self.is_empty() {
+            return 0.0
+        }
+
+        (self.len() - self.count_nonzero()) as f64 / self.len() as f64
+    }
+
+    fn is_finite(&self) -> bool
+    where
+        T: Float {
+        self.iter().all(|value| value.is_finite())
+    }
+
+    fn has_nan(&self) -> bool
+    where
+        T: Float {
+        self.iter().any(|value| value.is_nan())
+    }
+
+    fn first_non_finite(&self) -> Option<usize>
+    where
+        T: Float {
+        self.iter().position(|value| !value.is_finite())
+    }
+
+    /// True if the dot product is within `epsilon` of zero. Panics if the
+    /// lengths differ.
+    fn is_orthogonal_to(&self, other: &impl VectorType<T>, epsilon: T) -> bool
+    where
+        T: Float {
+        self.dot(other).abs() <= epsilon
+    }
+
+    /// True if the cosine of the angle between the vectors is within `epsilon`
+    /// of `1` or `-1`. A zero vector counts as parallel to everything. Panics
+    /// if the lengths differ.
+    fn is_parallel_to(&self, other: &impl VectorType<T>, epsilon: T) -> bool
+    where
+        T: Float {
+        let norms = self.norm() * other.norm();
+        if norms.is_zero() {
+            return true
+        }
+
+        T::one() - (self.dot(other) / norms).abs() <= epsilon
+    }
+
+    #[cfg(feature = "full")]
+    fn normalize(&self) -> Vector<T>
+    where
+        T: Float {
+        let norm = self.norm();
+        Vector::from(
+            self.iter()
+                .map(|value| *value / norm)
+                .collect::<Vec<T>>()
+        )
+    }
+
+    /// Like `normalize`, but returns `None` instead of dividing when the norm
+    /// is below `epsilon` (or `NaN`).
+    #[cfg(feature = "full")]
+    fn try_normalize(&self, epsilon: T) -> Option<Vector<T>>
+    where
+        T: Float {
+        let norm = self.norm();
+        if norm.is_nan() || norm < epsilon {
+            return None
+        }
+
+        Some(Vector::from(
+            self.iter()
+                .map(|value| *value / norm)
+                .collect::<Vec<T>>()
+        ))
+    }
+
+    /// Scales the vector to have L2 norm `target_norm`. A zero vector has no
+    /// direction to scale along, so it is returned unchanged.
+    #[cfg(feature = "full")]
+    fn rescale(&self, target_norm: T) -> Vector<T>
+    where
+        T: Float {
+        let norm = self.norm();
+        let scale = if norm.is_zero() { T::one() } else { target_norm / norm };
+        Vector::from(
+            self.iter()
+                .map(|value| *value * scale)
+                .collect::<Vec<T>>()
+        )
+    }
+
+    /// Affinely maps the elements so the smallest becomes `lo` and the largest
+    /// becomes `hi`. If every element is equal the range is zero, and every
+    /// output is `lo`.
+    #[cfg(feature = "full")]
+    fn min_max_scale(&self, lo: T, hi: T) -> Vector<T>
+    where
+        T: Float {
+        let (min, max) = match self.min_max() {
+            Some((min, max)) => (*min, *max),
+            None => return Vector::from(vec![])
+        };
+        let range = max - min;
+        Vector::from(
+            self.iter()
+                .map(|value| if range.is_zero() { lo } else { lo + (*value - min) / range * (hi - lo) })
+                .collect::<Vec<T>>()
+        )
+    }
+
+    /// Softmax, computed stably by subtracting the maximum before exponentiating.
+    #[cfg(feature = "full")]
+    fn softmax(&self) -> Vector<T>
+    where
+        T: Float {
+        let max = self.iter().fold(T::neg_infinity(), |max, value| max.max(*value));
+        let exps = self.iter().map(|value| (*value - max).exp()).collect::<Vec<T>>();
+        let sum = exps.iter().fold(T::zero(), |sum, value| sum + *value);
+        Vector::from(
+            exps.into_iter()
+                .map(|value| value / sum)
+                .collect::<Vec<T>>()
+        )
+    }
+
+    /// Logarithm of `softmax`, computed directly as `x - max - ln(sum(exp(x - max)))`
+    /// to stay stable.
+    #[cfg(feature = "full")]
+    fn log_softmax(&self) -> Vector<T>
+    where
+        T: Float {
+        let max = self.iter().fold(T::neg_infinity(), |max, value| max.max(*value));
+        let log_sum = self.iter()
+            .fold(T::zero(), |sum, value| sum + (*value - max).exp())
+            .ln();
+        Vector::from(
+            self.iter()
+                .map(|value| *value - max - log_sum)
+                .collect::<Vec<T>>()
+        )
+    }
+
+    /// Projection of `self` onto the line spanned by `other`.
+    #[cfg(feature = "full")]
+    fn project_onto(&self, other: &impl VectorType<T>) -> Vector<T>
+    where
+        T: Float {
+        if self.len() != other.len() {
+            panic!("Cannot project onto a differently sized vector.")
+        }
+
+        let scale = self.dot(other) / other.norm_squared();
+        Vector::from(
+            other.iter()
+                .map(|value| *value * scale)
+                .collect::<Vec<T>>()
+        )
+    }
+
+    /// Mean of every sliding window of `window` elements, computed with a
+    /// running sum in `O(n)`.
+    ///
+    /// The output has length `self.len() - window + 1`, and is empty when
+    /// `window` is longer than the vector. Panics if `window` is zero.
+    #[cfg(feature = "full")]
+    fn moving_average(&self, window: usize) -> Vector<T>
+    where
+        T: Float {
+        if window == 0 {
+            panic!("Cannot take a moving average over a window of size zero.")
+        }
+        if window > self.len() {
+            return Vector::from(vec![])
+        }
+
+        let values = self.iter().as_slice();
+        let size = T::from(window).expect("Window size must be representable by the element type.");
+
+        let mut sum = values[..window].iter().fold(T::zero(), |sum, value| sum + *value);
+        let mut averages = Vec::with_capacity(values.len() - window + 1);
+        averages.push(sum / size);
+        for idx in window..values.len() {
+            sum = sum + values[idx] - values[idx - window];
+            averages.push(sum / size)
+        }
+        Vector::from(averages)
+    }
+
+    /// Applies `f` to each pair of elements. Panics if the lengths differ; see
+    /// `combine_zip` for a lenient version.
+    #[cfg(feature = "full")]
+    fn combine<F>(&self, other: &impl VectorType<T>, f: F) -> Vector<T>
+    where
+        F: Fn(&T, &T) -> T {
+        if self.len() != other.len() {
+            panic!("Vectors with different sizes cannot be combined.")
+        }
+
+        self.combine_zip(other, f)
+    }
+
+    /// Like `combine`, but only pairs elements up to the shorter length, so
+    /// the output is as long as the shorter input.
+    #[cfg(feature = "full")]
+    fn combine_zip<F>(&self, other: &impl VectorType<T>, f: F) -> Vector<T>
+    where
+        F: Fn(&T, &T) -> T {
+        Vector::from(
+            self.iter()
+                .zip(other.iter())
+                .map(|(lhs, rhs)| f(lhs, rhs))
+                .collect::<Vec<T>>()
+        )
+    }
+
+    /// Like `combine`, but treats the shorter input as if padded with `pad` up
+    /// to the longer length, so the output is as long as the longer input.
+    ///
+    /// With `f` as addition and `pad` as zero, this is exactly polynomial
+    /// addition of two coefficient vectors.
+    #[cfg(feature = "full")]
+    fn combine_padded<F>(&self, other: &impl VectorType<T>, pad: T, f: F) -> Vector<T>
+    where
+        T: Clone,
+        F: Fn(&T, &T) -> T {
+        let length = self.len().max(other.len());
+        let lhs = self.iter().as_slice();
+        let rhs = other.iter().as_slice();
+
+        let mut values = Vec::with_capacity(length);
+        for idx in 0..length {
+            values.push(f(
+                lhs.get(idx).unwrap_or(&pad),
+                rhs.get(idx).unwrap_or(&pad)
+            ))
+        }
+        Vector::from(values)
+    }
+
+    /// Flattened Kronecker product, where `out[i * m + j] = self[i] * other[j]`
+    /// for an `other` of length `m`.
+    #[cfg(feature = "full")]
+    fn kron(&self, other: &impl VectorType<T>) -> Vector<T>
+    where
+        T: Clone + Mul<Output = T> {
+        let mut values = Vec::with_capacity(self.len() * other.len());
+        for lhs in self.iter() {
+            for rhs in other.iter() {
+                values.push(lhs.clone() * rhs.clone())
+            }
+        }
+        Vector::from(values)
+    }
+
+    /// Alternates elements of `self` and `other`, producing
+    /// `[a0, b0, a1, b1, ...]`. Panics if the lengths differ.
+    #[cfg(feature = "full")]
+    fn interleave(&self, other: &impl VectorType<T>) -> Vector<T>
+    where
+        T: Clone {
+        if self.len() != other.len() {
+            panic!("Vectors with different sizes cannot be interleaved.")
+        }
+
+        let mut values = Vec::with_capacity(self.len() * 2);
+        for (lhs, rhs) in self.iter().zip(other.iter()) {
+            values.push(lhs.clone());
+            values.push(rhs.clone())
+        }
+        Vector::from(values)
+    }
+
+    /// Splits the elements at even and odd indices into two vectors, undoing
+    /// `interleave`. For odd lengths the extra last element goes to the first.
+    #[cfg(feature = "full")]
+    fn deinterleave(&self) -> (Vector<T>, Vector<T>)
+    where
+        T: Clone {
+        let mut evens = Vec::with_capacity(self.len().div_ceil(2));
+        let mut odds = Vec::with_capacity(self.len() / 2);
+        for (index, value) in self.iter().enumerate() {
+            if index % 2 == 0 {
+                evens.push(value.clone())
+            } else {
+                odds.push(value.clone())
+            }
+        }
+        (Vector::from(evens), Vector::from(odds))
+    }
+
+    /// `n` back-to-back copies of the vector, so `[1, 2].tile(2)` is
+    /// `[1, 2, 1, 2]`.
+    #[cfg(feature = "full")]
+    fn tile(&self, n: usize) -> Vector<T>
+    where
+        T: Clone {
+        let mut values = Vec::with_capacity(self.len() * n);
+        for _ in 0..n {
+            values.extend(self.iter().cloned())
+        }
+        Vector::from(values)
+    }
+
+    /// Each element repeated `n` times in place, so `[1, 2].repeat_each(2)` is
+    /// `[1, 1, 2, 2]`.
+    #[cfg(feature = "full")]
+    fn repeat_each(&self, n: usize) -> Vector<T>
+    where
+        T: Clone {
+        let mut values = Vec::with_capacity(self.len() * n);
+        for value in self.iter() {
+            for _ in 0..n {
+                values.push(value.clone())
+            }
+        }
+        Vector::from(values)
+    }
+
+    /// Copies the vector into a fresh `MutVector` for in-place work.
+    #[cfg(feature = "full")]
+    fn to_mut_vector(&self) -> MutVector<T>
+    where
+        T: Clone {
+        MutVector::from(self.iter().as_slice().to_vec())
+    }
+
+    /// Copy of the vector truncated or padded with `fill` to `new_len` elements.
+    #[cfg(feature = "full")]
+    fn resized(&self, new_len: usize, fill: T) -> Vector<T>
+    where
+        T: Clone {
+        let mut values = Vec::with_capacity(new_len);
+        values.extend(self.iter().take(new_len).cloned());
+        values.resize(new_len, fill);
+        Vector::from(values)
+    }
+
+    /// Appends a `1`, turning a point into homogeneous coordinates.
+    #[cfg(feature = "full")]
+    fn to_homogeneous(&self) -> Vector<T>
+    where
+        T: Clone + One {
+        let mut values = Vec::with_capacity(self.len() + 1);
+        values.extend(self.iter().cloned());
+        values.push(T::one());
+        Vector::from(values)
+    }
+
+    /// Divides by the last component and drops it (the perspective divide).
+    ///
+    /// If the last component is zero the vector is a point at infinity (a
+    /// direction), so the leading components are returned as-is.
+    #[cfg(feature = "full")]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_homogeneous(&self) -> Vector<T>
+    where
+        T: Clone + Zero + Div<Output = T> {
+        let (w, rest) = match self.iter().as_slice().split_last() {
+            Some(split) => split,
+            None => panic!("Cannot convert an empty vector from homogeneous coordinates.")
+        };
+
+        if w.is_zero() {
+            Vector::from(rest.to_vec())
+        } else {
+            Vector::from(
+                rest.iter()
+                    .map(|value| value.clone() / w.clone())
+                    .collect::<Vec<T>>()
+            )
+        }
+    }
+
+    /// Splits the vector into consecutive, non-overlapping slices with the
+    /// given `sizes`. Errors unless the sizes sum to exactly `self.len()`; a sum
+    /// that overflows is reported as a `total` of `usize::MAX`.
+    #[cfg(feature = "full")]
+    fn split_into(&self, sizes: &[usize]) -> Result<Vec<VectorSlice<'_, T>>, SplitError> {
+        let total = sizes.iter().try_fold(0usize, |total, &size| total.checked_add(size));
+        if total != Some(self.len()) {
+            return Err(SplitError { len: self.len(), total: total.unwrap_or(usize::MAX) })
+        }
+
+        let mut rest = self.iter().as_slice();
+        let mut slices = Vec::with_capacity(sizes.len());
+        for &size in sizes {
+            let (segment, remainder) = rest.split_at(size);
+            slices.push(VectorSlice::from(segment));
+            rest = remainder
+        }
+        Ok(slices)
+    }
+
+    /// Splits the vector at every element matching `pred`, yielding the
+    /// segments between them. As with `slice::split`, the delimiters are
+    /// dropped and adjacent delimiters yield empty segments.
+    #[cfg(feature = "no_std")]
+    fn split<'v, F>(&'v self, pred: F) -> impl Iterator<Item = VectorSlice<'v, T>>
+    where
+        T: 'v,
+        F: Fn(&T) -> bool {
+        self.iter()
+            .as_slice()
+            .split(pred)
+            .map(VectorSlice::from)
+    }
+
+    /// Yields maximal runs of consecutive elements for which `same` holds
+    /// between each neighbouring pair.
+    #[cfg(feature = "no_std")]
+    fn group_by<'v, F>(&'v self, same: F) -> impl Iterator<Item = VectorSlice<'v, T>>
+    where
+        T: 'v,
+        F: FnMut(&T, &T) -> bool {
+        self.iter()
+            .as_slice()
+            .chunk_by(same)
+            .map(VectorSlice::from)
+    }
+
+    /// Dot products of corresponding `chunk`-sized blocks, so the output has
+    /// `len / chunk` elements. Errors unless `chunk` is non-zero and divides
+    /// the length evenly; panics if the lengths differ.
+    #[cfg(feature = "full")]
+    fn chunk_dot(&self, other: &impl VectorType<T>, chunk: usize) -> Result<Vector<T>, ChunkError>
+    where
+        T: Clone + Zero + Mul<Output = T> {
+        if self.len() != other.len() {
+            panic!("Cannot find dot product of two differently sized vectors.")
+        }
+        if chunk == 0 || self.len() % chunk != 0 {
+            return Err(ChunkError { len: self.len(), chunk })
+        }
+
+        Ok(Vector::from(
+            self.iter().as_slice()
+                .chunks_exact(chunk)
+                .zip(other.iter().as_slice().chunks_exact(chunk))
+                .map(|(lhs, rhs)| lhs.dot(&rhs))
+                .collect::<Vec<T>>()
+        ))
+    }
+
+    /// Indices that would sort the vector ascending. The sort is stable.
+    /// Elements that are incomparable even with themselves (such as `NaN`) are
+    /// placed after all others, in order of appearance.
+    #[cfg(feature = "full")]
+    fn argsort(&self) -> Vector<usize>
+    where
+        T: PartialOrd {
+        let values = self.iter().as_slice();
+        let (mut indices, incomparable): (Vec<usize>, Vec<usize>) = (0..values.len())
+            .partition(|&idx| values[idx].partial_cmp(&values[idx]).is_some());
+        indices.sort_by(|&lhs, &rhs| {
+            values[lhs]
+                .partial_cmp(&values[rhs])
+                .unwrap_or(Ordering::Equal)
+        });
+        indices.extend(incomparable);
+        Vector::from(indices)
+    }
+
+    /// Zero-based position of each element in ascending order. Ties are ranked
+    /// in order of appearance, matching `argsort`.
+    #[cfg(feature = "full")]
+    fn rank(&self) -> Vector<usize>
+    where
+        T: PartialOrd {
+        let mut ranks = vec![0; self.len()];
+        for (rank, &idx) in self.argsort().iter().enumerate() {
+            ranks[idx] = rank
+        }
+        Vector::from(ranks)
+    }
+
+    /// Smallest and largest elements in one pass, comparing elements in pairs
+    /// for about `3n/2` comparisons. Returns `None` if the vector is empty.
+    fn min_max(&self) -> Option<(&T, &T)>
+    where
+        T: PartialOrd {
+        let values = self.iter().as_slice();
+        let (first, rest) = values.split_first()?;
+        let (mut min, mut max) = (first, first);
+
+        let mut pairs = rest.chunks_exact(2);
+        for pair in pairs.by_ref() {
+            let (small, large) = if pair[1] < pair[0] {
+                (&pair[1], &pair[0])
+            } else {
+                (&pair[0], &pair[1])
+            };
+            if small < min {
+                min = small
+            }
+            if large > max {
+                max = large
+            }
+        }
+        if let [last] = pairs.remainder() {
+            if last < min {
+                min = last
+            } else if last > max {
+                max = last
+            }
+        }
+        Some((min, max))
+    }
+
+    /// Running maximum, so `out[i]` is the largest of `self[..=i]`.
+    #[cfg(feature = "full")]
+    fn cummax(&self) -> Vector<T>
+    where
+        T: Clone + PartialOrd {
+        running_extreme(self, |value, current| value > current)
+    }
+
+    /// Running minimum, so `out[i]` is the smallest of `self[..=i]`.
+    #[cfg(feature = "full")]
+    fn cummin(&self) -> Vector<T>
+    where
+        T: Clone + PartialOrd {
+        running_extreme(self, |value, current| value < current)
+    }
+
+    /// Maps elements through `f` until it first returns `None`, keeping the
+    /// prefix mapped so far. The output may therefore be shorter than `self`.
+    #[cfg(feature = "full")]
+    fn map_while<F, O>(&self, f: F) -> Vector<O>
+    where
+        F: Fn(&T) -> Option<O> {
+        Vector::from(
+            self.iter()
+                .map_while(f)
+                .collect::<Vec<O>>()
+        )
+    }
+
+    /// Element-wise comparisons producing boolean masks. Panics if the lengths
+    /// differ.
+    #[cfg(feature = "full")]
+    fn lt_mask(&self, other: &impl VectorType<T>) -> Vector<bool>
+    where
+        T: PartialOrd {
+        compare(self, other, |lhs, rhs| lhs < rhs)
+    }
+
+    #[cfg(feature = "full")]
+    fn le_mask(&self, other: &impl VectorType<T>) -> Vector<bool>
+    where
+        T: PartialOrd {
+        compare(self, other, |lhs, rhs| lhs <= rhs)
+    }
+
+    #[cfg(feature = "full")]
+    fn gt_mask(&self, other: &impl VectorType<T>) -> Vector<bool>
+    where
+        T: PartialOrd {
+        compare(self, other, |lhs, rhs| lhs > rhs)
+    }
+
+    #[cfg(feature = "full")]
+    fn ge_mask(&self, other: &impl VectorType<T>) -> Vector<bool>
+    where
+        T: PartialOrd {
+        compare(self, other, |lhs, rhs| lhs >= rhs)
+    }
+
+    #[cfg(feature = "full")]
+    fn eq_elementwise(&self, other: &impl VectorType<T>) -> Vector<bool>
+    where
+        T: PartialEq {
+        compare(self, other, |lhs, rhs| lhs == rhs)
+    }
+
+    /// Compares every element against `scalar`, producing a boolean mask.
+    #[cfg(feature = "full")]
+    fn lt_scalar(&self, scalar: T) -> Vector<bool>
+    where
+        T: PartialOrd {
+        Vector::from(
+            self.iter()
+                .map(|value| *value < scalar)
+                .collect::<Vec<bool>>()
+        )
+    }
+
+    #[cfg(feature = "full")]
+    fn le_scalar(&self, scalar: T) -> Vector<bool>
+    where
+        T: PartialOrd {
+        Vector::from(
+            self.iter()
+                .map(|value| *value <= scalar)
+                .collect::<Vec<bool>>()
+        )
+    }
+
+    #[cfg(feature = "full")]
+    fn gt_scalar(&self, scalar: T) -> Vector<bool>
+    where
+        T: PartialOrd {
+        Vector::from(
+            self.iter()
+                .map(|value| *value > scalar)
+                .collect::<Vec<bool>>()
+        )
+    }
+
+    #[cfg(feature = "full")]
+    fn ge_scalar(&self, scalar: T) -> Vector<bool>
+    where
+        T: PartialOrd {
+        Vector::from(
+            self.iter()
+                .map(|value| *value >= scalar)
+                .collect::<Vec<bool>>()
+        )
+    }
+
+    #[cfg(feature = "full")]
+    fn eq_scalar(&self, scalar: T) -> Vector<bool>
+    where
+        T: PartialEq {
+        Vector::from(
+            self.iter()
+                .map(|value| *value == scalar)
+                .collect::<Vec<bool>>()
+        )
+    }
+}
+
+impl<T> VectorType<T> for [T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        <[T]>::iter(self)
+    }
+}
+
+/// Lets plain references such as `&[T]` be passed wherever an
+/// `&impl VectorType<T>` is expected. A `Vec<T>` can be passed as
+/// `&v.as_slice()` or `&&v[..]`.
+impl<T, V> VectorType<T> for &V
+where
+    V: VectorType<T> + ?Sized {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        (**self).iter()
+    }
+}
+
+#[cfg(feature = "full")]
+fn compare<T, F>(lhs: &(impl VectorType<T> + ?Sized), rhs: &impl VectorType<T>, f: F) -> Vector<bool>
+where
+    F: Fn(&T, &T) -> bool {
+    if lhs.len() != rhs.len() {
+        panic!("Vectors with different sizes cannot be compared.")
+    }
+
+    Vector::from(
+        lhs.iter()
+            .zip(rhs.iter())
+            .map(|(lhs, rhs)| f(lhs, rhs))
+            .collect::<Vec<bool>>()
+    )
+}
+
+#[cfg(feature = "full")]
+fn running_extreme<T, F>(values: &(impl VectorType<T> + ?Sized), replaces: F) -> Vector<T>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> bool {
+    let mut extremes: Vec<T> = Vec::with_capacity(values.len());
+    for value in values.iter() {
+        let next = match extremes.last() {
+            Some(current) if !replaces(value, current) => current.clone(),
+            _ => value.clone()
+        };
+        extremes.push(next)
+    }
+    Vector::from(extremes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VectorType;
+
+    #[test]
+    fn checked_dot_overflow_is_none() {
+        let lhs: &[i32] = &[i32::MAX, 1];
+        let rhs: &[i32] = &[2, 1];
+
+        assert_eq!(lhs.checked_dot(&rhs), None);
+    }
+
+    #[test]
+    fn checked_dot_without_overflow() {
+        let lhs: &[i32] = &[1, 2, 3];
+        let rhs: &[i32] = &[4, -5, 6];
+
+        assert_eq!(lhs.checked_dot(&rhs), Some(12));
+    }
+
+    #[test]
+    #[cfg(feature = "full")]
+    fn argsort_places_nan_last() {
+        use alloc::vec::Vec;
+
+        let mut state = 0x2545_f491_u32;
+        let values = (0..200)
+            .map(|idx| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                if idx % 7 == 3 { f64::NAN } else { (state >> 8) as f64 }
+            })
+            .collect::<Vec<f64>>();
+        let values = values.as_slice();
+
+        let order = values.argsort();
+        let nan_count = values.iter().filter(|value| value.is_nan()).count();
+        let (sorted, nans) = order.iter().as_slice().split_at(values.len() - nan_count);
+
+        assert!(sorted.windows(2).all(|pair| values[pair[0]] <= values[pair[1]]));
+        assert!(nans.iter().all(|&idx| values[idx].is_nan()));
+        assert!(nans.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let ranks = values.rank();
+        assert!(order.iter().enumerate().all(|(rank, &idx)| ranks[idx] == rank));
+    }
+}
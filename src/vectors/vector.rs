@@ -1,15 +1,24 @@
 #![cfg(feature = "full")]
 
+use alloc::vec;
 use alloc::vec::Vec;
-use core::ops::{Range, Index};
-use super::{Vector, VectorSlice};
+use core::ops::{Range, Index, Mul};
+use core::slice::Iter;
+use num_traits::{Float, One, Zero};
+use crate::errors::{NonFiniteError, ShapeError};
+use crate::matricies::Matrix;
+use super::{Vector, MutVector, VectorSlice, MutVectorSlice, VectorType};
 
 impl<T> Vector<T> {
     pub fn len(&self) -> usize {
         self.values.len()
     }
 
-    pub fn as_slice<'v>(&'v self, range: Range<usize>) -> VectorSlice<'v, T> {
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn as_slice(&self, range: Range<usize>) -> VectorSlice<'_, T> {
         VectorSlice {
             values: self.values
                         .as_slice()
@@ -17,6 +26,148 @@ impl<T> Vector<T> {
                         .split_at(range.len()).0
         }
     }
+
+    pub fn basis(n: usize, i: usize) -> Vector<T>
+    where
+        T: Clone + Zero + One {
+        if i >= n {
+            panic!("Basis index {} is out of bounds for a vector of length {}.", i, n)
+        }
+
+        let mut values = vec![T::zero(); n];
+        values[i] = T::one();
+        Vector::from(values)
+    }
+
+    pub fn one_hot(n: usize, i: usize) -> Vector<T>
+    where
+        T: Clone + Zero + One {
+        Vector::basis(n, i)
+    }
+
+    /// Like `Vector::from`, but rejects vectors containing `NaN` or infinite
+    /// values, reporting the index of the first one.
+    pub fn try_new_finite(values: Vec<T>) -> Result<Self, NonFiniteError>
+    where
+        T: Float {
+        match values.iter().position(|value| !value.is_finite()) {
+            Some(index) => Err(NonFiniteError { index }),
+            None => Ok(Vector { values })
+        }
+    }
+
+    /// Collects `iter`, reserving room for `len_hint` elements up front. The
+    /// hint only affects allocation; the result holds every item yielded.
+    pub fn from_iter_sized(iter: impl IntoIterator<Item = T>, len_hint: usize) -> Vector<T> {
+        let mut values = Vec::with_capacity(len_hint);
+        values.extend(iter);
+        Vector { values }
+    }
+
+    /// Creates a buffer of `n` default values, lets `f` fill it in place, then
+    /// freezes it into a `Vector`.
+    pub fn build<F>(n: usize, f: F) -> Vector<T>
+    where
+        T: Clone + Default,
+        F: FnOnce(&mut MutVector<T>) {
+        Vector::from(vec![T::default(); n]).with_values(f)
+    }
+
+    /// Temporarily exposes the vector as a `MutVector` so `f` can mutate it in
+    /// place, then freezes it again. The allocation is reused throughout.
+    pub fn with_values<F>(self, f: F) -> Vector<T>
+    where
+        F: FnOnce(&mut MutVector<T>) {
+        let mut buffer = MutVector::from(self);
+        f(&mut buffer);
+        Vector::from(buffer)
+    }
+
+    pub fn flatten<I>(vectors: I) -> Vector<T>
+    where
+        I: IntoIterator<Item = Vector<T>> {
+        let vectors = vectors.into_iter().collect::<Vec<Vector<T>>>();
+
+        let mut values = Vec::with_capacity(
+            vectors.iter().map(|vector| vector.len()).sum()
+        );
+        for vector in vectors {
+            values.extend(vector.values)
+        }
+        Vector::from(values)
+    }
+
+    /// Reinterprets the vector as a `rows`x`cols` matrix in row-major order,
+    /// so element `(r, c)` is `self[r * cols + c]`. The backing storage is moved,
+    /// not copied.
+    pub fn reshape(self, rows: usize, cols: usize) -> Result<Matrix<T>, ShapeError> {
+        if rows.checked_mul(cols) != Some(self.len()) {
+            return Err(ShapeError { len: self.len(), rows, cols })
+        }
+
+        Ok(Matrix {
+            values: self.values,
+            rows,
+            cols,
+        })
+    }
+
+    pub fn retain<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&T) -> bool {
+        self.values.retain(f);
+        self
+    }
+
+    pub fn resize(&mut self, new_len: usize, fill: T) -> &mut Self
+    where
+        T: Clone {
+        self.values.resize(new_len, fill);
+        self
+    }
+
+    /// Removes consecutive repeated elements, as `Vec::dedup` does. Sort the
+    /// vector first to remove every duplicate.
+    pub fn dedup(&mut self) -> &mut Self
+    where
+        T: PartialEq {
+        self.values.dedup();
+        self
+    }
+
+    /// Like `dedup`, but removes each element for which `same(previous, current)`
+    /// holds, where `previous` is the last element kept.
+    pub fn dedup_by<F>(&mut self, mut same: F) -> &mut Self
+    where
+        F: FnMut(&T, &T) -> bool {
+        self.values.dedup_by(|current, previous| same(previous, current));
+        self
+    }
+
+    pub fn insert(&mut self, index: usize, value: T) -> &mut Self {
+        if index > self.values.len() {
+            panic!(
+                "Insertion index {} is out of bounds for a vector of length {}.",
+                index, self.values.len()
+            )
+        }
+
+        self.values.insert(index, value);
+        self
+    }
+
+    /// Removes the element at `index` in O(1) by moving the last element into
+    /// its place, so the order of the remaining elements is not preserved.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        if index >= self.values.len() {
+            panic!(
+                "Removal index {} is out of bounds for a vector of length {}.",
+                index, self.values.len()
+            )
+        }
+
+        self.values.swap_remove(index)
+    }
 }
 impl<T> Vector<T> {
     pub fn lambda<F>(&self, f: F) -> Vector<T>
@@ -25,7 +176,7 @@ impl<T> Vector<T> {
         Vector::from(
             self.values
                 .iter()
-                .map(|value| f(value))
+                .map(f)
                 .collect::<Vec<T>>()
         )
     }
@@ -53,6 +204,19 @@ impl<T> Vector<T> {
                     .collect::<Vec<T>>()
             )
     }
+
+    pub fn filter<F>(&self, f: F) -> Vector<T>
+    where
+        T: Clone,
+        F: Fn(&T) -> bool {
+        Vector::from(
+            self.values
+                .iter()
+                .filter(|value| f(value))
+                .cloned()
+                .collect::<Vec<T>>()
+        )
+    }
 }
 impl<T, U> From<U> for Vector<T>
 where
@@ -62,7 +226,33 @@ where
         Vector { values: values.into() }
     }
 }
-impl<'v, T> Index<usize> for Vector<T>
+impl<T> From<MutVector<T>> for Vector<T> {
+    fn from(vector: MutVector<T>) -> Self {
+        Vector { values: vector.values }
+    }
+}
+impl<'v, T> From<VectorSlice<'v, T>> for Vector<T>
+where
+    T: Clone {
+    fn from(vector: VectorSlice<'v, T>) -> Self {
+        Vector { values: vector.values.to_vec() }
+    }
+}
+impl<'v, T> From<MutVectorSlice<'v, T>> for Vector<T>
+where
+    T: Clone {
+    fn from(vector: MutVectorSlice<'v, T>) -> Self {
+        Vector { values: vector.values.to_vec() }
+    }
+}
+impl<'v, T> From<&MutVectorSlice<'v, T>> for Vector<T>
+where
+    T: Clone {
+    fn from(vector: &MutVectorSlice<'v, T>) -> Self {
+        Vector { values: vector.values.to_vec() }
+    }
+}
+impl<T> Index<usize> for Vector<T>
 where
     T: Clone {
     type Output = T;
@@ -70,4 +260,101 @@ where
     fn index(&self, index: usize) -> &Self::Output {
         &self.values[index]
     }
-}
\ No newline at end of file
+}
+impl<T> VectorType<T> for Vector<T> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        self.values.iter()
+    }
+}
+impl<T> Mul<T> for &Vector<T>
+where
+    T: Clone + Mul<Output = T> {
+    type Output = Vector<T>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Vector::from(
+            self.values
+                .iter()
+                .map(|value| value.clone() * scalar.clone())
+                .collect::<Vec<T>>()
+        )
+    }
+}
+impl<T> Mul<T> for Vector<T>
+where
+    T: Clone + Mul<Output = T> {
+    type Output = Vector<T>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Vector::from(
+            self.values
+                .into_iter()
+                .map(|value| value * scalar.clone())
+                .collect::<Vec<T>>()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vector;
+    use crate::vectors::{Vector, VectorSlice, MutVectorSlice, VectorType};
+
+    #[test]
+    fn from_vector_slice_copies() {
+        let mut raw = [1, 2, 3];
+        let vector = Vector::from(VectorSlice::from(&raw[..]));
+        raw[0] = 9;
+
+        assert_eq!(raw, [9, 2, 3]);
+        assert!(vector.iter().eq([1, 2, 3].iter()));
+    }
+
+    #[test]
+    fn from_mut_vector_slice_copies() {
+        let mut raw = [1, 2, 3];
+        let vector = Vector::from(MutVectorSlice::from(&mut raw[..]));
+        raw[0] = 9;
+
+        assert_eq!(raw, [9, 2, 3]);
+        assert!(vector.iter().eq([1, 2, 3].iter()));
+    }
+
+    #[test]
+    fn from_mut_vector_slice_ref_copies() {
+        let mut raw = [1, 2, 3];
+        let mut slice = MutVectorSlice::from(&mut raw[..]);
+        let vector = Vector::from(&slice);
+        slice.fill(0);
+
+        assert!(vector.iter().eq([1, 2, 3].iter()));
+        assert!(slice.iter().eq([0, 0, 0].iter()));
+    }
+
+    #[test]
+    fn scalar_scaling_composes_with_vector_ops() {
+        let a = vector![1, 2, 3];
+        let b = vector![10, 20, 30];
+
+        assert!((&a * 2 + &b).iter().eq([12, 24, 36].iter()));
+        assert!((&a * 2 - &b + &a * 3).iter().eq([-5, -10, -15].iter()));
+        assert_eq!(&a * 2 * &b, 280);
+
+        let fa = vector![1.0, 2.0];
+        let fb = vector![0.5, 0.25];
+        assert!((&fa * 0.5 + &fb).iter().eq([1.0, 1.25].iter()));
+    }
+
+    #[test]
+    fn by_value_chain_consumes_operands() {
+        let a = vector![1, 2];
+        let b = vector![3, 4];
+        let c = vector![5, 6];
+
+        assert!((a + b + c).iter().eq([9, 12].iter()));
+    }
+}
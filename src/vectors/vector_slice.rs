@@ -2,14 +2,19 @@ mod no_std {
     #![cfg(feature = "no_std")]
 
     use core::ops::{Range, Index};
-    use crate::vectors::VectorSlice;
+    use core::slice::Iter;
+    use crate::vectors::{VectorSlice, VectorType};
 
     impl<'v, T> VectorSlice<'v, T> {
         pub fn len(&self) -> usize {
             self.values.len()
         }
 
-        pub fn as_slice(&'v self, range: Range<usize>) -> VectorSlice<'v, T> {
+        pub fn is_empty(&self) -> bool {
+            self.values.is_empty()
+        }
+
+        pub fn as_slice(&self, range: Range<usize>) -> VectorSlice<'v, T> {
             VectorSlice {
                 values: self.values
                             .split_at(range.start).1
@@ -34,6 +39,15 @@ mod no_std {
             &self.values[index]
         }
     }
+    impl<'v, T> VectorType<T> for VectorSlice<'v, T> {
+        fn len(&self) -> usize {
+            self.values.len()
+        }
+
+        fn iter(&self) -> Iter<'_, T> {
+            self.values.iter()
+        }
+    }
 }
 
 mod full {
@@ -49,7 +63,7 @@ mod full {
             Vector::from(
                 self.values
                     .iter()
-                    .map(|value| f(value))
+                    .map(f)
                     .collect::<Vec<T>>()
             )
         }
@@ -77,5 +91,18 @@ mod full {
                         .collect::<Vec<T>>()
                 )
         }
+
+        pub fn filter<F>(&self, f: F) -> Vector<T>
+        where
+            T: Clone,
+            F: Fn(&T) -> bool {
+            Vector::from(
+                self.values
+                    .iter()
+                    .filter(|value| f(value))
+                    .cloned()
+                    .collect::<Vec<T>>()
+            )
+        }
     }
 }
\ No newline at end of file